@@ -0,0 +1,527 @@
+use std::{fmt::Display, str::FromStr};
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{
+    parse::Parse, punctuated::Punctuated, Attribute, Error, Lit, LitInt, LitStr, Meta,
+    MetaNameValue, NestedMeta, Path, Token, Type, WherePredicate,
+};
+
+/// A list of predicates of a where clause.
+pub type WherePredicates = Punctuated<WherePredicate, Token![,]>;
+
+const ATTRIBUTE_NAME: &str = "fingerprint";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Location {
+    Container,
+    Variant,
+    Field,
+}
+
+const CONTAINER_ARGUMENTS: &[&str] = &[
+    "version",
+    "domain",
+    "tag",
+    "named_fields",
+    "sorted_fields",
+    "bound",
+    "crate",
+    "remote",
+    "unsafe_discriminant",
+    "bytes",
+];
+const VARIANT_ARGUMENTS: &[&str] = &["tag"];
+const FIELD_ARGUMENTS: &[&str] = &[
+    "skip",
+    "with",
+    "as",
+    "rename",
+    "skip_if_default",
+    "skip_if",
+    "bound",
+    "when",
+    "canonical_float",
+];
+
+impl Location {
+    fn arguments(self) -> &'static [&'static str] {
+        match self {
+            Location::Container => CONTAINER_ARGUMENTS,
+            Location::Variant => VARIANT_ARGUMENTS,
+            Location::Field => FIELD_ARGUMENTS,
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Location::Container => "a struct, enum or union",
+            Location::Variant => "an enum variant",
+            Location::Field => "a field",
+        }
+    }
+}
+
+/// Arguments of `#[fingerprint(...)]` attributes placed on a struct, an enum or a union.
+#[derive(Default)]
+pub struct ContainerAttributes {
+    pub version: Option<u32>,
+    pub domain: Option<Domain>,
+    pub tag: Option<TagMode>,
+    pub named_fields: Option<Path>,
+    pub sorted_fields: Option<Path>,
+    pub bound: Option<WherePredicates>,
+    pub crate_path: Option<Path>,
+    pub remote: Option<Path>,
+    pub union_mode: Option<UnionMode>,
+}
+
+/// A domain separation string hashed before the contents of a type.
+pub enum Domain {
+    /// The name of the type, used when the attribute is written as `#[fingerprint(domain)]`.
+    TypeName,
+    Custom(String),
+}
+
+/// The way enum variants are identified in the hash.
+pub struct TagMode {
+    pub kind: TagKind,
+    pub path: Path,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TagKind {
+    /// The discriminant of the variant is hashed as an integer. This is the default.
+    Discriminant,
+    /// The name of the variant is hashed as a string.
+    Name,
+}
+
+/// The way the active field of a union is determined.
+pub enum UnionMode {
+    /// The expression is matched against the `when` patterns of the fields. The matching field is
+    /// read without checks, which is why the attribute is named `unsafe_discriminant`.
+    Discriminant { path: Path, expr: TokenStream },
+    /// The bytes of the union are hashed, regardless of which field is active.
+    Bytes { path: Path },
+}
+
+impl UnionMode {
+    pub fn path(&self) -> &Path {
+        match self {
+            UnionMode::Discriminant { path, .. } | UnionMode::Bytes { path } => path,
+        }
+    }
+}
+
+impl ContainerAttributes {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = ContainerAttributes::default();
+
+        for meta in get_arguments(attrs)? {
+            match &meta {
+                Meta::NameValue(value) if value.path.is_ident("version") => {
+                    set_int_value(&mut result.version, value)?;
+                }
+                Meta::Path(path) if path.is_ident("domain") => {
+                    if result.domain.is_some() {
+                        return Err(duplicate_argument(path));
+                    }
+
+                    result.domain = Some(Domain::TypeName);
+                }
+                Meta::NameValue(value) if value.path.is_ident("domain") => {
+                    if result.domain.is_some() {
+                        return Err(duplicate_argument(&value.path));
+                    }
+
+                    let domain = get_string_value(value)?;
+                    result.domain = Some(Domain::Custom(domain.value()));
+                }
+                Meta::Path(path) if path.is_ident("named_fields") => {
+                    set_flag_path(&mut result.named_fields, path)?;
+                }
+                Meta::Path(path) if path.is_ident("sorted_fields") => {
+                    set_flag_path(&mut result.sorted_fields, path)?;
+                }
+                Meta::NameValue(value) if value.path.is_ident("bound") => {
+                    set_bound(&mut result.bound, value)?;
+                }
+                Meta::NameValue(value) if value.path.is_ident("crate") => {
+                    set_value(&mut result.crate_path, value)?;
+                }
+                Meta::NameValue(value) if value.path.is_ident("remote") => {
+                    set_value(&mut result.remote, value)?;
+                }
+                Meta::NameValue(value) if value.path.is_ident("unsafe_discriminant") => {
+                    set_union_mode(
+                        &mut result.union_mode,
+                        &value.path,
+                        UnionMode::Discriminant {
+                            path: value.path.clone(),
+                            expr: parse_string_value(value)?,
+                        },
+                    )?;
+                }
+                Meta::Path(path) if path.is_ident("bytes") => {
+                    set_union_mode(
+                        &mut result.union_mode,
+                        path,
+                        UnionMode::Bytes { path: path.clone() },
+                    )?;
+                }
+                Meta::NameValue(value) if value.path.is_ident("tag") => {
+                    if result.tag.is_some() {
+                        return Err(duplicate_argument(&value.path));
+                    }
+
+                    let lit = get_string_value(value)?;
+                    let kind = match lit.value().as_str() {
+                        "discriminant" => TagKind::Discriminant,
+                        "name" => TagKind::Name,
+                        _ => {
+                            return Err(Error::new_spanned(
+                                lit,
+                                "expected `\"discriminant\"` or `\"name\"`",
+                            ))
+                        }
+                    };
+
+                    result.tag = Some(TagMode {
+                        kind,
+                        path: value.path.clone(),
+                    });
+                }
+                _ => return Err(unexpected_argument(&meta, Location::Container)),
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Gets the path to the `fingerprint_struct` crate used by the generated code.
+    pub fn crate_path(&self) -> TokenStream {
+        match &self.crate_path {
+            Some(path) => path.to_token_stream(),
+            None => quote!(::fingerprint_struct),
+        }
+    }
+}
+
+/// Arguments of `#[fingerprint(...)]` attributes placed on an enum variant.
+#[derive(Default)]
+pub struct VariantAttributes {
+    pub tag: Option<VariantTag>,
+}
+
+/// A tag overriding the default identification of an enum variant.
+pub enum VariantTag {
+    Integer(LitInt),
+    Name(LitStr),
+}
+
+impl VariantAttributes {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = VariantAttributes::default();
+
+        for meta in get_arguments(attrs)? {
+            match &meta {
+                Meta::NameValue(value) if value.path.is_ident("tag") => {
+                    if result.tag.is_some() {
+                        return Err(duplicate_argument(&value.path));
+                    }
+
+                    result.tag = Some(match &value.lit {
+                        Lit::Int(lit) => VariantTag::Integer(lit.clone()),
+                        Lit::Str(lit) => VariantTag::Name(lit.clone()),
+                        lit => return Err(Error::new_spanned(
+                            lit,
+                            "fingerprint attribute `tag` expects an integer or a string literal",
+                        )),
+                    });
+                }
+                _ => return Err(unexpected_argument(&meta, Location::Variant)),
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Arguments of `#[fingerprint(...)]` attributes placed on a field.
+#[derive(Default)]
+pub struct FieldAttributes {
+    pub skip: bool,
+    pub with: Option<Path>,
+    pub as_type: Option<Type>,
+    pub rename: Option<LitStr>,
+    pub skip_if_default: bool,
+    pub skip_if: Option<Path>,
+    pub bound: Option<WherePredicates>,
+    pub when: Option<TokenStream>,
+    /// Whether floats are hashed canonically, and if so, whether `-0.0` is hashed like `0.0`.
+    pub canonical_float: Option<bool>,
+}
+
+impl FieldAttributes {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = FieldAttributes::default();
+        let mut modes: Vec<(&str, TokenStream)> = Vec::new();
+        let mut conditions: Vec<(&str, TokenStream)> = Vec::new();
+
+        for meta in get_arguments(attrs)? {
+            match &meta {
+                Meta::Path(path) if path.is_ident("skip") => {
+                    set_flag(&mut result.skip, path)?;
+                    modes.push(("skip", meta.to_token_stream()));
+                    conditions.push(("skip", meta.to_token_stream()));
+                }
+                Meta::Path(path) if path.is_ident("skip_if_default") => {
+                    set_flag(&mut result.skip_if_default, path)?;
+                    conditions.push(("skip_if_default", meta.to_token_stream()));
+                }
+                Meta::NameValue(value) if value.path.is_ident("bound") => {
+                    set_bound(&mut result.bound, value)?;
+                }
+                Meta::NameValue(value) if value.path.is_ident("skip_if") => {
+                    set_value(&mut result.skip_if, value)?;
+                    conditions.push(("skip_if", meta.to_token_stream()));
+                }
+                Meta::NameValue(value) if value.path.is_ident("with") => {
+                    set_value(&mut result.with, value)?;
+                    modes.push(("with", meta.to_token_stream()));
+                }
+                Meta::NameValue(value) if value.path.is_ident("as") => {
+                    set_value(&mut result.as_type, value)?;
+                    modes.push(("as", meta.to_token_stream()));
+                }
+                Meta::NameValue(value) if value.path.is_ident("when") => {
+                    set_value(&mut result.when, value)?;
+                }
+                Meta::Path(path) if path.is_ident("canonical_float") => {
+                    if result.canonical_float.is_some() {
+                        return Err(duplicate_argument(path));
+                    }
+
+                    result.canonical_float = Some(false);
+                    modes.push(("canonical_float", meta.to_token_stream()));
+                }
+                Meta::NameValue(value) if value.path.is_ident("canonical_float") => {
+                    if result.canonical_float.is_some() {
+                        return Err(duplicate_argument(&value.path));
+                    }
+
+                    let lit = get_string_value(value)?;
+                    if lit.value() != "normalize_zero" {
+                        return Err(Error::new_spanned(lit, "expected `\"normalize_zero\"`"));
+                    }
+
+                    result.canonical_float = Some(true);
+                    modes.push(("canonical_float", meta.to_token_stream()));
+                }
+                Meta::NameValue(value) if value.path.is_ident("rename") => {
+                    if result.rename.is_some() {
+                        return Err(duplicate_argument(&value.path));
+                    }
+
+                    result.rename = Some(get_string_value(value)?.clone());
+                }
+                _ => return Err(unexpected_argument(&meta, Location::Field)),
+            }
+        }
+
+        for arguments in [&modes, &conditions] {
+            if let [(first, _), (second, span), ..] = arguments.as_slice() {
+                return Err(conflicting_arguments(span, first, second));
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+fn get_arguments(attrs: &[Attribute]) -> syn::Result<Vec<Meta>> {
+    let mut arguments = Vec::new();
+
+    for attr in attrs.iter().filter(|a| a.path.is_ident(ATTRIBUTE_NAME)) {
+        match attr.parse_meta()? {
+            Meta::List(list) => {
+                for nested in list.nested {
+                    match nested {
+                        NestedMeta::Meta(meta) => arguments.push(meta),
+                        NestedMeta::Lit(lit) => {
+                            return Err(Error::new_spanned(
+                                lit,
+                                "expected a fingerprint attribute argument, found a literal",
+                            ))
+                        }
+                    }
+                }
+            }
+            meta => {
+                return Err(Error::new_spanned(
+                    meta,
+                    "expected an argument list, like `#[fingerprint(...)]`",
+                ))
+            }
+        }
+    }
+
+    Ok(arguments)
+}
+
+fn set_flag(flag: &mut bool, path: &Path) -> syn::Result<()> {
+    if *flag {
+        return Err(duplicate_argument(path));
+    }
+
+    *flag = true;
+    Ok(())
+}
+
+fn set_flag_path(flag: &mut Option<Path>, path: &Path) -> syn::Result<()> {
+    if flag.is_some() {
+        return Err(duplicate_argument(path));
+    }
+
+    *flag = Some(path.clone());
+    Ok(())
+}
+
+fn set_bound(bound: &mut Option<WherePredicates>, meta: &MetaNameValue) -> syn::Result<()> {
+    if bound.is_some() {
+        return Err(duplicate_argument(&meta.path));
+    }
+
+    *bound = Some(get_string_value(meta)?.parse_with(Punctuated::parse_terminated)?);
+    Ok(())
+}
+
+fn set_union_mode(mode: &mut Option<UnionMode>, path: &Path, value: UnionMode) -> syn::Result<()> {
+    if let Some(previous) = mode {
+        let first = path_to_string(previous.path());
+        let second = path_to_string(path);
+
+        if first == second {
+            return Err(duplicate_argument(path));
+        }
+
+        return Err(conflicting_arguments(path, &first, &second));
+    }
+
+    *mode = Some(value);
+    Ok(())
+}
+
+fn set_value<T: Parse>(value: &mut Option<T>, meta: &MetaNameValue) -> syn::Result<()> {
+    if value.is_some() {
+        return Err(duplicate_argument(&meta.path));
+    }
+
+    *value = Some(parse_string_value(meta)?);
+    Ok(())
+}
+
+fn set_int_value<T>(value: &mut Option<T>, meta: &MetaNameValue) -> syn::Result<()>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    if value.is_some() {
+        return Err(duplicate_argument(&meta.path));
+    }
+
+    *value = Some(parse_int_value(meta)?);
+    Ok(())
+}
+
+fn parse_int_value<T>(meta: &MetaNameValue) -> syn::Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    match &meta.lit {
+        Lit::Int(lit) => lit.base10_parse().map_err(|error| {
+            Error::new_spanned(
+                lit,
+                format!(
+                    "invalid value for fingerprint attribute `{}`: {error}",
+                    path_to_string(&meta.path)
+                ),
+            )
+        }),
+        lit => Err(Error::new_spanned(
+            lit,
+            format!(
+                "fingerprint attribute `{}` expects an integer literal",
+                path_to_string(&meta.path)
+            ),
+        )),
+    }
+}
+
+fn parse_string_value<T: Parse>(meta: &MetaNameValue) -> syn::Result<T> {
+    get_string_value(meta)?.parse()
+}
+
+fn get_string_value(meta: &MetaNameValue) -> syn::Result<&LitStr> {
+    match &meta.lit {
+        Lit::Str(lit) => Ok(lit),
+        lit => Err(Error::new_spanned(
+            lit,
+            format!(
+                "fingerprint attribute `{}` expects a string literal",
+                path_to_string(&meta.path)
+            ),
+        )),
+    }
+}
+
+fn duplicate_argument(path: &Path) -> Error {
+    Error::new_spanned(
+        path,
+        format!("duplicate fingerprint attribute `{}`", path_to_string(path)),
+    )
+}
+
+fn conflicting_arguments(span: impl ToTokens, first: &str, second: &str) -> Error {
+    Error::new_spanned(
+        span,
+        format!("fingerprint attributes `{first}` and `{second}` cannot be used together"),
+    )
+}
+
+fn unexpected_argument(meta: &Meta, location: Location) -> Error {
+    let path = meta.path();
+    let name = path_to_string(path);
+
+    if location.arguments().contains(&name.as_str()) {
+        let message = match meta {
+            Meta::Path(_) => format!("fingerprint attribute `{name}` expects a value"),
+            Meta::NameValue(_) => format!("fingerprint attribute `{name}` does not take a value"),
+            Meta::List(_) => format!("fingerprint attribute `{name}` does not take a list"),
+        };
+
+        return Error::new_spanned(meta, message);
+    }
+
+    let other = [Location::Container, Location::Variant, Location::Field]
+        .into_iter()
+        .find(|l| l.arguments().contains(&name.as_str()));
+
+    let message = match other {
+        Some(other) => format!(
+            "fingerprint attribute `{name}` cannot be used on {}, only on {}",
+            location.description(),
+            other.description()
+        ),
+        None => format!("unknown fingerprint attribute `{name}`"),
+    };
+
+    Error::new_spanned(path, message)
+}
+
+pub fn path_to_string(path: &Path) -> String {
+    let segments: Vec<String> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+    segments.join("::")
+}
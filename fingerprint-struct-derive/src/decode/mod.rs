@@ -0,0 +1,278 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{ext::IdentExt, Attribute, Data, DataEnum, DeriveInput, Error, Fields};
+
+use crate::{
+    attributes::{ContainerAttributes, Domain, FieldAttributes, TagKind},
+    enums::{check_unique_tags, discriminant::get_int_repr, get_tag_condition, get_tags},
+    fields::{check_not_union_fields, get_hashed_fields},
+    generics::{get_decode_where_bounds, get_generic_parameters, GenericParamType},
+    utils::{get_unnamed_field_name, input_arg},
+};
+
+pub fn expand_fingerprint_decode(input: DeriveInput) -> syn::Result<TokenStream> {
+    let DeriveInput {
+        ident,
+        data,
+        attrs,
+        generics,
+        ..
+    } = input;
+
+    let attributes = ContainerAttributes::parse(&attrs)?;
+    let input_arg = input_arg();
+    let crate_path = attributes.crate_path();
+
+    if let Some(remote) = &attributes.remote {
+        return Err(unsupported_argument(remote, "remote"));
+    }
+
+    let where_bounds = get_decode_where_bounds(&generics, &data, &attributes)?;
+    let generic_params_impl = get_generic_parameters(&generics, GenericParamType::Impl);
+    let generic_params_type = get_generic_parameters(&generics, GenericParamType::Type);
+
+    let prefix = get_prefix(&attributes, &ident);
+    let body = match data {
+        Data::Struct(data) => get_fields_decode(&data.fields, &attributes, quote!(Self))?,
+        Data::Enum(data) => get_enum_decode(data, &ident, &attrs, &attributes)?,
+        Data::Union(data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                "FingerprintDecode can't be derived for unions",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl <#generic_params_impl> #crate_path::FingerprintDecode for #ident <#generic_params_type> where #where_bounds {
+            fn decode(#input_arg: &mut &[::core::primitive::u8]) -> ::core::result::Result<Self, #crate_path::DecodeError> {
+                #prefix
+                #body
+            }
+        }
+    })
+}
+
+/// Generates statements that check the domain and version preceding the fields or tag.
+fn get_prefix(attributes: &ContainerAttributes, name: &Ident) -> TokenStream {
+    let input_arg = input_arg();
+    let crate_path = attributes.crate_path();
+    let mut statements = TokenStream::new();
+
+    if let Some(domain) = &attributes.domain {
+        let domain = match domain {
+            Domain::TypeName => name.unraw().to_string(),
+            Domain::Custom(domain) => domain.clone(),
+        };
+
+        statements.extend(quote! {
+            #crate_path::__private::decode_expected(#input_arg, #domain)?;
+        });
+    }
+
+    if let Some(version) = attributes.version {
+        statements.extend(quote! {
+            #crate_path::__private::decode_expected(#input_arg, &#version)?;
+        });
+    }
+
+    statements
+}
+
+/// Generates an expression that decodes the tag of an enum, followed by the fields of the
+/// variant it identifies.
+fn get_enum_decode(
+    data: DataEnum,
+    name: &Ident,
+    attrs: &[Attribute],
+    attributes: &ContainerAttributes,
+) -> syn::Result<TokenStream> {
+    let input_arg = input_arg();
+    let crate_path = attributes.crate_path();
+
+    if data.variants.is_empty() {
+        return Ok(quote! {
+            ::core::result::Result::Err(#crate_path::DecodeError::InvalidValue)
+        });
+    }
+
+    let int_repr = get_int_repr(attrs)?;
+    let tag_ident = Ident::new("tag", Span::mixed_site());
+
+    let decode_tag = match attributes.tag.as_ref().map(|tag| tag.kind) {
+        Some(TagKind::Name) => quote! {
+            let #tag_ident: &::core::primitive::str = #crate_path::__private::decode_str(#input_arg)?;
+        },
+        _ => quote! {
+            let #tag_ident: ::core::primitive::#int_repr =
+                #crate_path::FingerprintDecode::decode(#input_arg)?;
+        },
+    };
+
+    let mut branches = TokenStream::new();
+    let mut tags = Vec::new();
+
+    for (variant, tag) in get_tags(data.variants, attributes)? {
+        let ident = &variant.ident;
+        let condition = get_tag_condition(&tag, &int_repr, &tag_ident);
+        let body = get_fields_decode(&variant.fields, attributes, quote!(Self::#ident))?;
+
+        branches.extend(quote! {
+            if #condition {
+                #body
+            } else
+        });
+        tags.push(tag);
+    }
+
+    let tag_check = check_unique_tags(&tags, name, &int_repr)?;
+
+    Ok(quote! {
+        #tag_check
+        #decode_tag
+
+        #branches {
+            ::core::result::Result::Err(#crate_path::DecodeError::InvalidValue)
+        }
+    })
+}
+
+/// Generates statements that decode all fields of a struct or enum variant in the order in which
+/// they are hashed, followed by an expression constructing it using `constructor`.
+fn get_fields_decode(
+    fields: &Fields,
+    container: &ContainerAttributes,
+    constructor: TokenStream,
+) -> syn::Result<TokenStream> {
+    let input_arg = input_arg();
+    let crate_path = container.crate_path();
+    let named = matches!(fields, Fields::Named(_));
+
+    let attributes = fields
+        .iter()
+        .map(|field| FieldAttributes::parse(&field.attrs))
+        .collect::<syn::Result<Vec<_>>>()?;
+    check_not_union_fields(&attributes)?;
+
+    for attributes in &attributes {
+        if let Some(with) = &attributes.with {
+            return Err(unsupported_argument(with, "with"));
+        }
+
+        if let Some(as_type) = &attributes.as_type {
+            return Err(unsupported_argument(as_type, "as"));
+        }
+
+        if let Some(skip_if) = &attributes.skip_if {
+            return Err(unsupported_argument(skip_if, "skip_if"));
+        }
+    }
+
+    let hashed_fields = get_hashed_fields(fields, &attributes, container)?;
+    let invalid = quote!(::core::result::Result::Err(#crate_path::DecodeError::InvalidValue));
+    let mut statements = TokenStream::new();
+
+    // If any field might be left out, the number of present fields comes first and every field
+    // is preceded by its name
+    let conditional = hashed_fields
+        .iter()
+        .any(|field| field.attributes.skip_if_default);
+    let remaining = Ident::new("remaining", Span::mixed_site());
+
+    if conditional {
+        statements.extend(quote! {
+            let mut #remaining: ::core::primitive::usize =
+                #crate_path::FingerprintDecode::decode(#input_arg)?;
+        });
+    }
+
+    for field in hashed_fields {
+        let binding = get_unnamed_field_name(field.index);
+        let name = &field.name;
+        let ty = &field.field.ty;
+        let value = quote!(<#ty as #crate_path::FingerprintDecode>::decode(#input_arg)?);
+
+        if field.attributes.skip_if_default {
+            // Names are unique, so the field is present exactly if the next field starts with
+            // its name
+            statements.extend(quote! {
+                let #binding: #ty = match #remaining > 0
+                    && #crate_path::__private::decode_expected(#input_arg, #name).is_ok()
+                {
+                    true => {
+                        let #binding: #ty = #value;
+
+                        if #binding == <#ty as ::core::default::Default>::default() {
+                            return #invalid;
+                        }
+
+                        #remaining -= 1;
+                        #binding
+                    }
+                    false => ::core::default::Default::default(),
+                };
+            });
+            continue;
+        }
+
+        if conditional {
+            statements.extend(quote! {
+                #remaining = match #remaining.checked_sub(1) {
+                    ::core::option::Option::Some(#remaining) => #remaining,
+                    ::core::option::Option::None => return #invalid,
+                };
+                #crate_path::__private::decode_expected(#input_arg, #name)?;
+            });
+        } else if named && container.named_fields.is_some() {
+            statements.extend(quote! {
+                #crate_path::__private::decode_expected(#input_arg, #name)?;
+            });
+        }
+
+        statements.extend(quote! {
+            let #binding: #ty = #value;
+        });
+    }
+
+    if conditional {
+        statements.extend(quote! {
+            if #remaining != 0 {
+                return #invalid;
+            }
+        });
+    }
+
+    let values = fields
+        .iter()
+        .zip(&attributes)
+        .enumerate()
+        .map(|(index, (field, attributes))| {
+            let value = match attributes.skip {
+                true => quote!(::core::default::Default::default()),
+                false => get_unnamed_field_name(index).to_token_stream(),
+            };
+
+            match &field.ident {
+                Some(ident) => quote!(#ident: #value),
+                None => value,
+            }
+        });
+
+    let value = match fields {
+        Fields::Named(_) => quote!(#constructor { #(#values),* }),
+        Fields::Unnamed(_) => quote!(#constructor ( #(#values),* )),
+        Fields::Unit => constructor,
+    };
+
+    Ok(quote! {
+        #statements
+        ::core::result::Result::Ok(#value)
+    })
+}
+
+fn unsupported_argument(span: impl ToTokens, name: &str) -> Error {
+    Error::new_spanned(
+        span,
+        format!("fingerprint attribute `{name}` isn't supported by FingerprintDecode"),
+    )
+}
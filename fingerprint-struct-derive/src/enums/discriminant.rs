@@ -1,32 +1,54 @@
 use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::{quote, ToTokens};
-use syn::{Attribute, Expr, Meta, NestedMeta, Path, Variant};
+use syn::{
+    Attribute, Error, Expr, ExprLit, ExprParen, ExprUnary, Lit, Meta, NestedMeta, Path, UnOp,
+    Variant,
+};
+
+/// The discriminant of an enum variant.
+pub struct Discriminant {
+    /// An expression evaluating to the discriminant.
+    pub tokens: TokenStream,
+    /// The value of the discriminant, if it can be determined without evaluating any constants.
+    pub value: Option<i128>,
+}
 
 pub struct AddDiscriminant<T: Iterator<Item = Variant>> {
     iterator: T,
-    discriminant: Option<Expr>,
+    discriminant: Option<(Expr, Option<i128>)>,
     offset: usize,
 }
 
 impl<T: Iterator<Item = Variant>> Iterator for AddDiscriminant<T> {
-    type Item = (Variant, TokenStream);
+    type Item = (Variant, Discriminant);
 
     fn next(&mut self) -> Option<Self::Item> {
         match self.iterator.next() {
             Some(next) => {
-                let discriminant: TokenStream = match next.discriminant.clone() {
+                let discriminant = match next.discriminant.clone() {
                     Some((_, discriminant)) => {
-                        self.discriminant = Some(discriminant.clone());
+                        let value = evaluate_literal(&discriminant);
+
+                        self.discriminant = Some((discriminant.clone(), value));
                         self.offset = 0;
 
-                        quote!(#discriminant)
+                        Discriminant {
+                            tokens: quote!(#discriminant),
+                            value,
+                        }
                     }
                     None => {
                         let offset = Literal::usize_unsuffixed(self.offset);
 
                         match &self.discriminant {
-                            Some(discriminant) => quote!(#discriminant + #offset),
-                            None => quote!(#offset),
+                            Some((discriminant, value)) => Discriminant {
+                                tokens: quote!(#discriminant + #offset),
+                                value: value.and_then(|v| v.checked_add(self.offset as i128)),
+                            },
+                            None => Discriminant {
+                                tokens: quote!(#offset),
+                                value: Some(self.offset as i128),
+                            },
                         }
                     }
                 };
@@ -48,36 +70,74 @@ pub fn add_discriminant<T: Iterator<Item = Variant>>(iterator: T) -> AddDiscrimi
     }
 }
 
+/// Evaluates an integer literal, optionally negated.
+pub fn evaluate_literal(expr: &Expr) -> Option<i128> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse().ok(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => evaluate_literal(expr).and_then(i128::checked_neg),
+        Expr::Paren(ExprParen { expr, .. }) => evaluate_literal(expr),
+        _ => None,
+    }
+}
+
 const PRIMITIVE_NAMES: [&str; 12] = [
     "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
 ];
 
-pub fn get_int_repr(attrs: Vec<Attribute>) -> TokenStream {
+/// Gets the integer type of the discriminant from the `#[repr]` attributes of an enum.
+pub fn get_int_repr(attrs: &[Attribute]) -> syn::Result<TokenStream> {
     let mut repr: Option<Path> = None;
 
-    for meta in attrs.iter().flat_map(extract_repr_arguments) {
-        if let NestedMeta::Meta(Meta::Path(path)) = meta {
-            if PRIMITIVE_NAMES.iter().any(|p| check_ident_name(&path, p)) {
-                repr = Some(path);
+    for attr in attrs.iter().filter(|a| check_ident_name(&a.path, "repr")) {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            meta => {
+                return Err(Error::new_spanned(
+                    meta,
+                    "expected a list of representation hints, like `#[repr(u8)]`",
+                ))
+            }
+        };
+
+        for nested in list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::Path(path))
+                    if PRIMITIVE_NAMES.iter().any(|p| check_ident_name(&path, p)) =>
+                {
+                    if let Some(previous) = &repr {
+                        return Err(Error::new_spanned(
+                            &path,
+                            format!(
+                                "conflicting representation hints `{}` and `{}`",
+                                previous.to_token_stream(),
+                                path.to_token_stream()
+                            ),
+                        ));
+                    }
+
+                    repr = Some(path);
+                }
+                NestedMeta::Lit(lit) => {
+                    return Err(Error::new_spanned(
+                        lit,
+                        "expected a representation hint, found a literal",
+                    ))
+                }
+                NestedMeta::Meta(_) => {}
             }
         }
     }
 
-    match repr {
+    Ok(match repr {
         Some(repr) => repr.to_token_stream(),
         None => quote!(isize),
-    }
-}
-
-fn extract_repr_arguments(attr: &Attribute) -> Vec<NestedMeta> {
-    if !check_ident_name(&attr.path, "repr") {
-        return Vec::new();
-    }
-
-    match attr.parse_meta() {
-        Ok(Meta::List(list)) => list.nested.into_iter().collect(),
-        _ => Vec::new(),
-    }
+    })
 }
 
 fn check_ident_name(path: &Path, name: &str) -> bool {
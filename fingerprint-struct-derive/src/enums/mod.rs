@@ -1,104 +1,164 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
-use syn::{punctuated::Punctuated, token::Comma, Attribute, DataEnum, Fields, Variant};
-
-use crate::utils::{get_field_names, get_unnamed_field_names, hasher_arg};
-
-use self::discriminant::{add_discriminant, get_int_repr};
-
-mod discriminant;
-
-pub fn get_enum_fn_body(data: DataEnum, name: &Ident, attrs: Vec<Attribute>) -> TokenStream {
+use syn::{punctuated::Punctuated, token::Comma, Attribute, DataEnum, Variant};
+
+use crate::{
+    attributes::{ContainerAttributes, FieldAttributes, TagKind, VariantAttributes},
+    fields::{
+        check_not_union_fields, get_field_binding, get_field_type_checks, get_fields_body,
+        get_fields_pattern,
+    },
+    utils::{hasher_arg, remote_pattern_path, value_arg},
+};
+
+use self::{
+    discriminant::{add_discriminant, get_int_repr},
+    tags::{get_tag, Tag, TagValue},
+};
+
+pub use self::tags::check_unique_tags;
+
+pub mod discriminant;
+mod tags;
+
+pub fn get_enum_fn_body(
+    data: DataEnum,
+    name: &Ident,
+    attrs: &[Attribute],
+    attributes: &ContainerAttributes,
+) -> syn::Result<TokenStream> {
     let DataEnum { variants, .. } = data;
 
     if variants.is_empty() {
-        return TokenStream::default();
+        return Ok(TokenStream::default());
     }
 
-    let int_repr = get_int_repr(attrs);
-
-    let variants = variants.into_iter();
-    let variants = add_discriminant(variants);
-
-    let arms = variants.map(|(v, d)| get_match_arm(v, d, name.clone(), &int_repr));
+    let int_repr = get_int_repr(attrs)?;
+    let mut arms = TokenStream::new();
+    let mut tags = Vec::new();
+
+    for (variant, tag) in get_tags(variants, attributes)? {
+        arms.extend(get_match_arm(
+            variant,
+            &tag,
+            name.clone(),
+            &int_repr,
+            attributes,
+        )?);
+        tags.push(tag);
+    }
 
-    let arms: TokenStream = arms.collect();
+    let tag_check = check_unique_tags(&tags, name, &int_repr)?;
+    let value = match &attributes.remote {
+        Some(_) => {
+            let value_arg = value_arg();
+            quote!(#value_arg)
+        }
+        None => quote!(self),
+    };
 
-    quote! {
-        match self {
+    Ok(quote! {
+        #tag_check
+        match #value {
             #arms
         }
-    }
+    })
 }
 
-fn get_match_arm(
-    variant: Variant,
-    discriminant: TokenStream,
-    enum_name: Ident,
+/// Gets the tag identifying each variant of an enum in the hash.
+pub fn get_tags(
+    variants: Punctuated<Variant, Comma>,
+    attributes: &ContainerAttributes,
+) -> syn::Result<Vec<(Variant, Tag)>> {
+    let tag_kind = match &attributes.tag {
+        Some(tag) => tag.kind,
+        None => TagKind::Discriminant,
+    };
+
+    add_discriminant(variants.into_iter())
+        .map(|(variant, discriminant)| {
+            let variant_attributes = VariantAttributes::parse(&variant.attrs)?;
+            let tag = get_tag(tag_kind, &variant, variant_attributes, discriminant)?;
+            Ok((variant, tag))
+        })
+        .collect()
+}
+
+/// Generates a statement that updates the hasher with the tag of a variant.
+pub fn get_tag_statement(
+    tag: &Tag,
     int_repr: &TokenStream,
+    crate_path: &TokenStream,
 ) -> TokenStream {
-    let Variant { ident, fields, .. } = variant;
-
-    let body = get_match_body(&fields);
-    let pattern = get_match_pattern(&fields);
-
     let hasher_arg = hasher_arg();
 
-    quote!(
-        #enum_name::#ident #pattern => {
+    match &tag.value {
+        TagValue::Integer { tokens, .. } => quote! {
             {
-                let discriminant: ::core::primitive::#int_repr = #discriminant;
-                discriminant.fingerprint(#hasher_arg);
+                let discriminant: ::core::primitive::#int_repr = #tokens;
+                #crate_path::Fingerprint::fingerprint(&discriminant, #hasher_arg);
             }
-            #body
-        }
-    )
+        },
+        TagValue::Name(name) => quote! {
+            #crate_path::Fingerprint::fingerprint(#name, #hasher_arg);
+        },
+    }
 }
 
-fn get_match_pattern(fields: &Fields) -> TokenStream {
-    match fields {
-        Fields::Named(fields) => {
-            let idents = get_field_names(fields);
-            let body: Punctuated<_, Comma> = Punctuated::from_iter(idents);
-
-            quote!({#body})
-        }
-        Fields::Unnamed(fields) => {
-            let idents = get_unnamed_field_names(fields);
-            let body: Punctuated<_, Comma> = Punctuated::from_iter(idents);
-
-            quote!((#body))
-        }
-        Fields::Unit => TokenStream::default(),
+/// Generates an expression that evaluates to `true` if a decoded tag identifies a variant.
+///
+/// The `value` must be a variable holding either an integer of type `int_repr` or a `&str`,
+/// depending on the kind of the tag.
+pub fn get_tag_condition(tag: &Tag, int_repr: &TokenStream, value: &Ident) -> TokenStream {
+    match &tag.value {
+        TagValue::Integer { tokens, .. } => quote! {
+            #value == {
+                let discriminant: ::core::primitive::#int_repr = #tokens;
+                discriminant
+            }
+        },
+        TagValue::Name(name) => quote!(#value == #name),
     }
 }
 
-fn get_match_body(fields: &Fields) -> TokenStream {
-    let hasher_arg = hasher_arg();
-
-    match fields {
-        Fields::Named(fields) => {
-            let idents = get_field_names(fields);
-
-            let statements = idents.map(|ident| {
-                quote! {
-                    #ident.fingerprint(#hasher_arg);
-                }
-            });
-
-            statements.collect()
-        }
-        Fields::Unnamed(fields) => {
-            let idents = get_unnamed_field_names(fields);
-
-            let statements = idents.map(|ident| {
-                quote! {
-                    #ident.fingerprint(#hasher_arg);
-                }
-            });
+fn get_match_arm(
+    variant: Variant,
+    tag: &Tag,
+    enum_name: Ident,
+    int_repr: &TokenStream,
+    attributes: &ContainerAttributes,
+) -> syn::Result<TokenStream> {
+    let Variant { ident, fields, .. } = variant;
 
-            statements.collect()
+    let field_attributes = fields
+        .iter()
+        .map(|field| FieldAttributes::parse(&field.attrs))
+        .collect::<syn::Result<Vec<_>>>()?;
+    check_not_union_fields(&field_attributes)?;
+
+    let body = get_fields_body(&fields, &field_attributes, attributes, get_field_binding)?;
+
+    // Remote enums bind all fields to check that they match the definition of the remote type
+    let (enum_path, pattern, type_checks) = match &attributes.remote {
+        Some(remote) => (
+            remote_pattern_path(remote),
+            get_fields_pattern(&fields, &field_attributes, true)?,
+            get_field_type_checks(&fields),
+        ),
+        None => (
+            quote!(#enum_name),
+            get_fields_pattern(&fields, &field_attributes, false)?,
+            TokenStream::default(),
+        ),
+    };
+
+    let tag = get_tag_statement(tag, int_repr, &attributes.crate_path());
+
+    Ok(quote!(
+        #enum_path::#ident #pattern => {
+            #type_checks
+            #tag
+            #body
         }
-        Fields::Unit => TokenStream::default(),
-    }
+    ))
 }
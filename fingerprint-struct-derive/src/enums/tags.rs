@@ -0,0 +1,146 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{ext::IdentExt, Error, Variant};
+
+use crate::attributes::{TagKind, VariantAttributes, VariantTag};
+
+use super::discriminant::Discriminant;
+
+/// The value identifying an enum variant in the hash.
+pub struct Tag {
+    pub value: TagValue,
+    /// Whether the tag was set explicitly using `#[fingerprint(tag = ...)]`.
+    pub overridden: bool,
+    pub span: TokenStream,
+}
+
+pub enum TagValue {
+    Integer {
+        tokens: TokenStream,
+        value: Option<i128>,
+    },
+    Name(String),
+}
+
+pub fn get_tag(
+    kind: TagKind,
+    variant: &Variant,
+    attributes: VariantAttributes,
+    discriminant: Discriminant,
+) -> syn::Result<Tag> {
+    let ident = &variant.ident;
+
+    match (kind, attributes.tag) {
+        (TagKind::Discriminant, None) => Ok(Tag {
+            value: TagValue::Integer {
+                tokens: discriminant.tokens,
+                value: discriminant.value,
+            },
+            overridden: false,
+            span: ident.to_token_stream(),
+        }),
+        (TagKind::Discriminant, Some(VariantTag::Integer(lit))) => Ok(Tag {
+            value: TagValue::Integer {
+                tokens: lit.to_token_stream(),
+                value: lit.base10_parse().ok(),
+            },
+            overridden: true,
+            span: lit.to_token_stream(),
+        }),
+        (TagKind::Discriminant, Some(VariantTag::Name(lit))) => Err(Error::new_spanned(
+            lit,
+            "string tags can only be used when the enum has `#[fingerprint(tag = \"name\")]`",
+        )),
+        (TagKind::Name, None) => Ok(Tag {
+            value: TagValue::Name(ident.unraw().to_string()),
+            overridden: false,
+            span: ident.to_token_stream(),
+        }),
+        (TagKind::Name, Some(VariantTag::Name(lit))) => Ok(Tag {
+            value: TagValue::Name(lit.value()),
+            overridden: true,
+            span: lit.to_token_stream(),
+        }),
+        (TagKind::Name, Some(VariantTag::Integer(lit))) => Err(Error::new_spanned(
+            lit,
+            "integer tags cannot be used when the enum has `#[fingerprint(tag = \"name\")]`",
+        )),
+    }
+}
+
+/// Makes sure that no two variants share the same tag.
+///
+/// Returns an error if a duplicate tag is found. If the tags can't be compared without evaluating
+/// constant expressions, a compile time assertion is generated instead.
+pub fn check_unique_tags(
+    tags: &[Tag],
+    enum_name: &Ident,
+    int_repr: &TokenStream,
+) -> syn::Result<TokenStream> {
+    for (index, tag) in tags.iter().enumerate() {
+        for other in &tags[..index] {
+            let duplicate = match (&tag.value, &other.value) {
+                (TagValue::Name(a), TagValue::Name(b)) => a == b,
+                (
+                    TagValue::Integer { value: Some(a), .. },
+                    TagValue::Integer { value: Some(b), .. },
+                ) => a == b,
+                _ => false,
+            };
+
+            if duplicate {
+                let span = if other.overridden && !tag.overridden {
+                    &other.span
+                } else {
+                    &tag.span
+                };
+
+                return Err(Error::new_spanned(
+                    span,
+                    "multiple variants have the same fingerprint tag",
+                ));
+            }
+        }
+    }
+
+    let unknown = tags
+        .iter()
+        .any(|tag| matches!(tag.value, TagValue::Integer { value: None, .. }));
+    let overridden = tags.iter().any(|tag| tag.overridden);
+
+    if !unknown || !overridden {
+        return Ok(TokenStream::default());
+    }
+
+    let values = tags.iter().filter_map(|tag| match &tag.value {
+        TagValue::Integer { tokens, .. } => Some(tokens),
+        TagValue::Name(_) => None,
+    });
+    let count = tags.len();
+    let message = format!(
+        "multiple variants of `{}` have the same fingerprint tag",
+        enum_name.unraw()
+    );
+    let tags_ident = Ident::new("tags", Span::mixed_site());
+
+    Ok(quote! {
+        const _: () = {
+            let #tags_ident: [::core::primitive::#int_repr; #count] = [#(#values),*];
+            let mut i = 0;
+
+            while i < #count {
+                let mut j = i + 1;
+
+                while j < #count {
+                    if #tags_ident[i] == #tags_ident[j] {
+                        ::core::panic!(#message);
+                    }
+
+                    j += 1;
+                }
+
+                i += 1;
+            }
+        };
+    })
+}
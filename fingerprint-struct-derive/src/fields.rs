@@ -0,0 +1,330 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{ext::IdentExt, punctuated::Punctuated, token::Comma, Error, Field, Fields};
+
+use crate::{
+    attributes::{ContainerAttributes, FieldAttributes},
+    utils::{get_field_name, get_unnamed_field_name, hasher_arg},
+};
+
+/// A field that is hashed.
+pub struct HashedField<'a> {
+    /// The name of the field, taking renames into account. Unnamed fields are named after their
+    /// index.
+    pub name: String,
+    pub index: usize,
+    pub field: &'a Field,
+    pub attributes: &'a FieldAttributes,
+}
+
+/// Gets the fields of a struct or enum variant that aren't skipped, in the order in which they
+/// are hashed.
+pub fn get_hashed_fields<'a>(
+    fields: &'a Fields,
+    attributes: &'a [FieldAttributes],
+    container: &ContainerAttributes,
+) -> syn::Result<Vec<HashedField<'a>>> {
+    for (field, attributes) in fields.iter().zip(attributes) {
+        if let (None, Some(rename)) = (&field.ident, &attributes.rename) {
+            return Err(Error::new_spanned(
+                rename,
+                "fingerprint attribute `rename` can only be used on named fields",
+            ));
+        }
+    }
+
+    let mut hashed: Vec<_> = fields
+        .iter()
+        .enumerate()
+        .zip(attributes)
+        .filter(|(_, attributes)| !attributes.skip)
+        .map(|((index, field), attributes)| HashedField {
+            name: get_fingerprint_name(index, field, attributes),
+            index,
+            field,
+            attributes,
+        })
+        .collect();
+
+    if matches!(fields, Fields::Named(_)) {
+        for (position, field) in hashed.iter().enumerate() {
+            if hashed[..position]
+                .iter()
+                .any(|other| other.name == field.name)
+            {
+                let span = match &field.attributes.rename {
+                    Some(rename) => quote!(#rename),
+                    None => {
+                        let field = field.field;
+                        quote!(#field)
+                    }
+                };
+
+                return Err(Error::new_spanned(
+                    span,
+                    format!("multiple fields have the fingerprint name `{}`", field.name),
+                ));
+            }
+        }
+
+        if container.sorted_fields.is_some() {
+            hashed.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+    }
+
+    Ok(hashed)
+}
+
+/// Generates statements that update the hasher with all fields of a struct or enum variant.
+///
+/// The `access` function must return an expression that evaluates to a reference to a field,
+/// given the index and definition of the field.
+///
+/// If any field might be skipped, the number of fields that aren't skipped is hashed first and
+/// every field is preceded by its name, so that the encoding remains unambiguous.
+pub fn get_fields_body(
+    fields: &Fields,
+    attributes: &[FieldAttributes],
+    container: &ContainerAttributes,
+    access: impl Fn(usize, &Field) -> TokenStream,
+) -> syn::Result<TokenStream> {
+    let named = matches!(fields, Fields::Named(_));
+    let hasher_arg = hasher_arg();
+    let crate_path = container.crate_path();
+
+    let fields: Vec<_> = get_hashed_fields(fields, attributes, container)?
+        .into_iter()
+        .map(|hashed| {
+            let value = access(hashed.index, hashed.field);
+            let condition = get_skip_condition(&value, hashed.field, hashed.attributes);
+
+            (hashed, value, condition)
+        })
+        .collect();
+
+    let conditional = fields.iter().any(|(_, _, condition)| condition.is_some());
+    let mut body = TokenStream::new();
+
+    if conditional {
+        let mut count = TokenStream::new();
+
+        for (hashed, _, condition) in &fields {
+            let skip = get_skip_binding(hashed.index);
+
+            match condition {
+                Some(condition) => {
+                    body.extend(quote! {
+                        let #skip: ::core::primitive::bool = #condition;
+                    });
+                    count.extend(quote! {
+                        + <::core::primitive::usize as ::core::convert::From<_>>::from(!#skip)
+                    });
+                }
+                None => count.extend(quote!(+ 1)),
+            }
+        }
+
+        body.extend(quote! {
+            #crate_path::Fingerprint::fingerprint(&(0usize #count), #hasher_arg);
+        });
+    }
+
+    for (hashed, value, condition) in fields {
+        let HashedField {
+            name,
+            index,
+            attributes,
+            ..
+        } = hashed;
+
+        let name = if (named && container.named_fields.is_some()) || conditional {
+            quote! {
+                #crate_path::Fingerprint::fingerprint(#name, #hasher_arg);
+            }
+        } else {
+            TokenStream::default()
+        };
+
+        let value = get_field_statement(value, attributes, &crate_path);
+
+        body.extend(match condition {
+            Some(_) => {
+                let skip = get_skip_binding(index);
+
+                quote! {
+                    if !#skip {
+                        #name
+                        #value
+                    }
+                }
+            }
+            None => quote! {
+                #name
+                #value
+            },
+        });
+    }
+
+    Ok(body)
+}
+
+/// Gets the identifier of the local variable that holds whether a field is skipped.
+fn get_skip_binding(index: usize) -> Ident {
+    Ident::new(&format!("skip{index}"), Span::mixed_site())
+}
+
+/// Checks that union-only field attributes aren't used on fields of structs or enums.
+pub fn check_not_union_fields(attributes: &[FieldAttributes]) -> syn::Result<()> {
+    match attributes
+        .iter()
+        .find_map(|attributes| attributes.when.as_ref())
+    {
+        Some(when) => Err(Error::new_spanned(
+            when,
+            "fingerprint attribute `when` can only be used on fields of unions",
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Gets the name of a field used by `#[fingerprint(named_fields)]`, taking renames into account.
+///
+/// Unnamed fields are named after their index.
+fn get_fingerprint_name(index: usize, field: &Field, attributes: &FieldAttributes) -> String {
+    match (&attributes.rename, &field.ident) {
+        (Some(rename), _) => rename.value(),
+        (None, Some(ident)) => ident.unraw().to_string(),
+        (None, None) => index.to_string(),
+    }
+}
+
+/// Generates an expression that evaluates to `true` if the field should be skipped.
+fn get_skip_condition(
+    value: &TokenStream,
+    field: &Field,
+    attributes: &FieldAttributes,
+) -> Option<TokenStream> {
+    if attributes.skip_if_default {
+        let ty = &field.ty;
+
+        return Some(quote! {
+            #value == &<#ty as ::core::default::Default>::default()
+        });
+    }
+
+    attributes
+        .skip_if
+        .as_ref()
+        .map(|predicate| quote!(#predicate(#value)))
+}
+
+/// Generates a statement that updates the hasher with a field.
+///
+/// The `value` must be an expression that evaluates to a reference to the field.
+fn get_field_statement(
+    value: TokenStream,
+    attributes: &FieldAttributes,
+    crate_path: &TokenStream,
+) -> TokenStream {
+    let hasher_arg = hasher_arg();
+
+    if let Some(with) = &attributes.with {
+        return quote! {
+            #with(#value, #hasher_arg);
+        };
+    }
+
+    if let Some(as_type) = &attributes.as_type {
+        return quote! {
+            {
+                let value: #as_type = ::core::convert::Into::into(#value);
+                #crate_path::Fingerprint::fingerprint(&value, #hasher_arg);
+            }
+        };
+    }
+
+    if let Some(normalize_zero) = attributes.canonical_float {
+        return quote! {
+            #crate_path::FingerprintCanonical::fingerprint_canonical(#value, #hasher_arg, #normalize_zero);
+        };
+    }
+
+    quote! {
+        #crate_path::Fingerprint::fingerprint(#value, #hasher_arg);
+    }
+}
+
+/// Generates a pattern that destructures a struct or enum variant, binding each field that is
+/// hashed to the identifier returned by [`get_field_binding`].
+///
+/// Skipped fields are ignored, unless `bind_skipped` is set.
+pub fn get_fields_pattern(
+    fields: &Fields,
+    attributes: &[FieldAttributes],
+    bind_skipped: bool,
+) -> syn::Result<TokenStream> {
+    let bind = |attributes: &FieldAttributes| bind_skipped || !attributes.skip;
+
+    match fields {
+        Fields::Named(fields) => {
+            let idents = fields
+                .named
+                .iter()
+                .zip(attributes)
+                .filter(|(_, attributes)| bind(attributes))
+                .map(|(field, _)| get_field_name(field))
+                .collect::<syn::Result<Vec<_>>>()?;
+            let body: Punctuated<_, Comma> = Punctuated::from_iter(idents);
+
+            Ok(if attributes.iter().all(bind) {
+                quote!({#body})
+            } else if body.is_empty() {
+                quote!({ .. })
+            } else {
+                quote!({#body, ..})
+            })
+        }
+        Fields::Unnamed(_) => {
+            let idents = attributes.iter().enumerate().map(|(n, attributes)| {
+                if bind(attributes) {
+                    let ident = get_unnamed_field_name(n);
+                    quote!(#ident)
+                } else {
+                    quote!(_)
+                }
+            });
+            let body: Punctuated<_, Comma> = Punctuated::from_iter(idents);
+
+            Ok(quote!((#body)))
+        }
+        Fields::Unit => Ok(TokenStream::default()),
+    }
+}
+
+/// Gets the identifier a field is bound to by [`get_fields_pattern`].
+pub fn get_field_binding(index: usize, field: &Field) -> TokenStream {
+    match &field.ident {
+        Some(ident) => quote!(#ident),
+        None => {
+            let ident = get_unnamed_field_name(index);
+            quote!(#ident)
+        }
+    }
+}
+
+/// Generates statements that check that all fields bound by [`get_fields_pattern`] have the
+/// types they are declared with.
+pub fn get_field_type_checks(fields: &Fields) -> TokenStream {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let binding = get_field_binding(index, field);
+            let ty = &field.ty;
+
+            quote! {
+                let _: &#ty = #binding;
+            }
+        })
+        .collect()
+}
@@ -1,9 +1,11 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, TokenStream, TokenTree};
 use quote::{quote, ToTokens};
 use syn::{
     punctuated::Punctuated, ConstParam, Data, Fields, Generics, LifetimeDef, Token, TypeParam,
 };
 
+use crate::attributes::{ContainerAttributes, FieldAttributes, UnionMode};
+
 pub enum GenericParamType {
     Impl,
     Type,
@@ -23,7 +25,11 @@ pub fn get_generic_parameters(generics: &Generics, target: GenericParamType) ->
     params.to_token_stream()
 }
 
-pub fn get_where_bounds(generics: &Generics, data: &Data) -> TokenStream {
+pub fn get_where_bounds(
+    generics: &Generics,
+    data: &Data,
+    attributes: &ContainerAttributes,
+) -> syn::Result<TokenStream> {
     let mut bounds: Punctuated<TokenStream, Token!(,)> = Punctuated::new();
 
     if let Some(clause) = &generics.where_clause {
@@ -32,19 +38,143 @@ pub fn get_where_bounds(generics: &Generics, data: &Data) -> TokenStream {
 
     bounds.extend(get_where_bounds_from_params(generics));
 
+    if let Some(bound) = &attributes.bound {
+        bounds.extend(bound.iter().map(|b| b.to_token_stream()));
+        return Ok(bounds.to_token_stream());
+    }
+
     match data {
-        Data::Struct(data) => bounds.extend(get_where_bounds_from_fields(&data.fields)),
+        Data::Struct(data) => {
+            bounds.extend(get_where_bounds_from_fields(&data.fields, attributes)?)
+        }
         Data::Enum(data) => {
             for variant in data.variants.iter() {
-                bounds.extend(get_where_bounds_from_fields(&variant.fields));
+                bounds.extend(get_where_bounds_from_fields(&variant.fields, attributes)?);
             }
         }
-        Data::Union(_) => {}
+        Data::Union(data) => match attributes.union_mode {
+            Some(UnionMode::Bytes { .. }) => {
+                let crate_path = attributes.crate_path();
+
+                for field in data.fields.named.iter() {
+                    let ty = &field.ty;
+                    bounds.push(quote!(#ty: #crate_path::__private::NoPadding));
+                }
+            }
+            _ => {
+                let fields = Fields::Named(data.fields.clone());
+                bounds.extend(get_where_bounds_from_fields(&fields, attributes)?);
+            }
+        },
     };
 
+    Ok(bounds.to_token_stream())
+}
+
+/// Gets the where clause of a `FingerprintSchema` implementation, which requires each type
+/// parameter to implement `FingerprintSchema`.
+///
+/// Unlike `Fingerprint`, the bounds aren't inferred from the types of the fields, so recursive
+/// types don't need explicit bounds.
+pub fn get_schema_where_bounds(generics: &Generics, crate_path: &TokenStream) -> TokenStream {
+    let mut bounds: Punctuated<TokenStream, Token!(,)> = Punctuated::new();
+
+    if let Some(clause) = &generics.where_clause {
+        bounds.extend(clause.predicates.iter().map(|c| c.to_token_stream()));
+    }
+
+    bounds.extend(get_where_bounds_from_params(generics));
+
+    for param in generics.type_params() {
+        let ident = &param.ident;
+        bounds.push(quote!(#ident: #crate_path::FingerprintSchema));
+    }
+
     bounds.to_token_stream()
 }
 
+/// Gets the where clause of a `FingerprintDecode` implementation.
+///
+/// Like for `Fingerprint`, the bounds are inferred from the types of the fields. The types of
+/// fields that might be skipped must also implement `Default`.
+///
+/// The predicates given by `bound` attributes are written for `Fingerprint`, so they aren't used.
+/// Like for `FingerprintSchema`, the type parameters are required to implement
+/// `FingerprintDecode` instead, all of them for a container attribute and the ones used by the
+/// type of the field for a field attribute, so recursive types don't need other bounds.
+pub fn get_decode_where_bounds(
+    generics: &Generics,
+    data: &Data,
+    attributes: &ContainerAttributes,
+) -> syn::Result<TokenStream> {
+    let mut bounds: Punctuated<TokenStream, Token!(,)> = Punctuated::new();
+
+    if let Some(clause) = &generics.where_clause {
+        bounds.extend(clause.predicates.iter().map(|c| c.to_token_stream()));
+    }
+
+    bounds.extend(get_where_bounds_from_params(generics));
+
+    let crate_path = attributes.crate_path();
+    let mut decoded_params: Vec<&Ident> = Vec::new();
+
+    if attributes.bound.is_some() {
+        decoded_params.extend(generics.type_params().map(|param| &param.ident));
+    }
+
+    let fields: Vec<&Fields> = match data {
+        Data::Struct(data) => vec![&data.fields],
+        Data::Enum(data) => data.variants.iter().map(|v| &v.fields).collect(),
+        Data::Union(_) => Vec::new(),
+    };
+
+    for field in fields.into_iter().flatten() {
+        let field_attributes = FieldAttributes::parse(&field.attrs)?;
+        let ty = &field.ty;
+
+        if field_attributes.skip {
+            bounds.push(quote!(#ty: ::core::default::Default));
+            continue;
+        }
+
+        if field_attributes.skip_if_default {
+            bounds.push(quote!(#ty: ::core::default::Default + ::core::cmp::PartialEq));
+        }
+
+        if attributes.bound.is_some() {
+            continue;
+        }
+
+        if field_attributes.bound.is_some() {
+            for param in generics.type_params() {
+                if contains_ident(ty.to_token_stream(), &param.ident)
+                    && !decoded_params.contains(&&param.ident)
+                {
+                    decoded_params.push(&param.ident);
+                }
+            }
+            continue;
+        }
+
+        bounds.push(quote!(#ty: #crate_path::FingerprintDecode));
+    }
+
+    for ident in decoded_params {
+        bounds.push(quote!(#ident: #crate_path::FingerprintDecode));
+    }
+
+    Ok(bounds.to_token_stream())
+}
+
+/// Checks whether a token stream contains an identifier, including inside of groups.
+fn contains_ident(tokens: TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(token) => token == *ident,
+        TokenTree::Group(group) => contains_ident(group.stream(), ident),
+        _ => false,
+    })
+}
+
 fn get_where_bounds_from_params(generics: &Generics) -> Punctuated<TokenStream, Token!(,)> {
     let bounds: Punctuated<TokenStream, Token!(,)> = generics
         .params
@@ -73,22 +203,43 @@ fn get_where_bounds_from_params(generics: &Generics) -> Punctuated<TokenStream,
     bounds
 }
 
-fn get_where_bounds_from_fields(fields: &Fields) -> Punctuated<TokenStream, Token!(,)> {
-    let types = match fields {
-        Fields::Named(fields) => Some(fields.named.iter()),
-        Fields::Unnamed(fields) => Some(fields.unnamed.iter()),
-        Fields::Unit => None,
-    };
+fn get_where_bounds_from_fields(
+    fields: &Fields,
+    container: &ContainerAttributes,
+) -> syn::Result<Punctuated<TokenStream, Token!(,)>> {
+    let mut bounds: Punctuated<TokenStream, Token!(,)> = Punctuated::new();
+    let crate_path = container.crate_path();
+
+    for field in fields.iter() {
+        let attributes = FieldAttributes::parse(&field.attrs)?;
+
+        if attributes.skip {
+            continue;
+        }
+
+        if let Some(bound) = &attributes.bound {
+            bounds.extend(bound.iter().map(|b| b.to_token_stream()));
+            continue;
+        }
+
+        if attributes.skip_if_default {
+            let ty = &field.ty;
+            bounds.push(quote!(#ty: ::core::default::Default + ::core::cmp::PartialEq));
+        }
 
-    match types {
-        Some(types) => {
-            let bounds: Punctuated<TokenStream, Token!(,)> = types
-                .map(|field| field.ty.clone())
-                .map(|ty| quote!(#ty: ::fingerprint_struct::Fingerprint))
-                .collect();
+        if attributes.with.is_some() {
+            continue;
+        }
 
-            bounds
+        if attributes.canonical_float.is_some() {
+            let ty = &field.ty;
+            bounds.push(quote!(#ty: #crate_path::FingerprintCanonical));
+            continue;
         }
-        None => Punctuated::new(),
+
+        let ty = attributes.as_type.as_ref().unwrap_or(&field.ty);
+        bounds.push(quote!(#ty: #crate_path::Fingerprint));
     }
+
+    Ok(bounds)
 }
@@ -1,50 +1,141 @@
-//! This crate provides the implementation of the [`Fingerprint`] derive macro.
+//! This crate provides the implementation of the [`Fingerprint`], [`FingerprintSchema`] and
+//! [`FingerprintDecode`] derive macros.
 //!
 //! It's reexported by the `fingerprint-struct` crate when the `derive` feature flag is enabled.
 
+use attributes::ContainerAttributes;
+use decode::expand_fingerprint_decode;
 use generics::{get_generic_parameters, get_where_bounds, GenericParamType};
+use prefix::get_prefix;
+use proc_macro2::TokenStream;
 use quote::quote;
+use schema::expand_fingerprint_schema;
 use syn::{parse_macro_input, DeriveInput};
 
 use enums::get_enum_fn_body;
 use structs::get_struct_body;
-use utils::hasher_arg;
+use unions::get_union_body;
+use utils::{hasher_arg, value_arg};
 
+mod attributes;
+mod decode;
 mod enums;
+mod fields;
 mod generics;
+mod prefix;
+mod schema;
 mod structs;
+mod unions;
 mod utils;
 
-#[proc_macro_derive(Fingerprint)]
+#[proc_macro_derive(Fingerprint, attributes(fingerprint))]
 pub fn derive_fingerprint(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(tokens as DeriveInput);
 
+    expand_fingerprint(input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+#[proc_macro_derive(FingerprintSchema, attributes(fingerprint))]
+pub fn derive_fingerprint_schema(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+
+    expand_fingerprint_schema(input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+#[proc_macro_derive(FingerprintDecode, attributes(fingerprint))]
+pub fn derive_fingerprint_decode(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+
+    expand_fingerprint_decode(input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+fn expand_fingerprint(input: DeriveInput) -> syn::Result<TokenStream> {
     let DeriveInput {
         ident,
+        vis,
         data,
         attrs,
         generics,
-        ..
     } = input;
 
-    let where_bounds = get_where_bounds(&generics, &data);
+    let attributes = ContainerAttributes::parse(&attrs)?;
+
+    let where_bounds = get_where_bounds(&generics, &data, &attributes)?;
     let generic_params_impl = get_generic_parameters(&generics, GenericParamType::Impl);
     let generic_params_type = get_generic_parameters(&generics, GenericParamType::Type);
 
+    // Remote types are named after the type they mirror
+    let type_name = match attributes.remote.as_ref().and_then(|r| r.segments.last()) {
+        Some(segment) => segment.ident.clone(),
+        None => ident.clone(),
+    };
+    let prefix = get_prefix(&attributes, &type_name);
     let body = match data {
-        syn::Data::Struct(data) => get_struct_body(data),
-        syn::Data::Enum(data) => get_enum_fn_body(data, &ident, attrs),
-        syn::Data::Union(_) => quote!(compile_error!("cannot derive Fingerprint for an union")),
+        syn::Data::Struct(data) => {
+            if let Some(tag) = &attributes.tag {
+                return Err(syn::Error::new_spanned(
+                    &tag.path,
+                    "fingerprint attribute `tag` can only be used on enums",
+                ));
+            }
+
+            check_not_union(&attributes)?;
+            get_struct_body(data, &attributes)?
+        }
+        syn::Data::Enum(data) => {
+            check_not_union(&attributes)?;
+            get_enum_fn_body(data, &ident, &attrs, &attributes)?
+        }
+        syn::Data::Union(data) => {
+            if let Some(tag) = &attributes.tag {
+                return Err(syn::Error::new_spanned(
+                    &tag.path,
+                    "fingerprint attribute `tag` can only be used on enums",
+                ));
+            }
+
+            get_union_body(data, &ident, &generics, &attributes)?
+        }
     };
 
     let hasher_arg = hasher_arg();
+    let crate_path = attributes.crate_path();
 
-    quote! {
-        impl <#generic_params_impl> ::fingerprint_struct::Fingerprint for #ident <#generic_params_type> where #where_bounds {
-            fn fingerprint<U: ::digest::Update>(&self, #hasher_arg: &mut U) {
+    if let Some(remote) = &attributes.remote {
+        let value_arg = value_arg();
+
+        return Ok(quote! {
+            impl <#generic_params_impl> #ident <#generic_params_type> where #where_bounds {
+                #vis fn fingerprint<U: #crate_path::digest::Update>(#value_arg: &#remote, #hasher_arg: &mut U) {
+                    #prefix
+                    #body
+                }
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl <#generic_params_impl> #crate_path::Fingerprint for #ident <#generic_params_type> where #where_bounds {
+            fn fingerprint<U: #crate_path::digest::Update>(&self, #hasher_arg: &mut U) {
+                #prefix
                 #body
             }
         }
+    })
+}
+
+fn check_not_union(attributes: &ContainerAttributes) -> syn::Result<()> {
+    match &attributes.union_mode {
+        Some(mode) => Err(syn::Error::new_spanned(
+            mode.path(),
+            "fingerprint attributes `unsafe_discriminant` and `bytes` can only be used on unions",
+        )),
+        None => Ok(()),
     }
-    .into()
 }
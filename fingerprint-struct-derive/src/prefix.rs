@@ -0,0 +1,34 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::ext::IdentExt;
+
+use crate::{
+    attributes::{ContainerAttributes, Domain},
+    utils::hasher_arg,
+};
+
+/// Generates statements that update the hasher before any fields or discriminants are hashed.
+pub fn get_prefix(attributes: &ContainerAttributes, name: &Ident) -> TokenStream {
+    let hasher_arg = hasher_arg();
+    let crate_path = attributes.crate_path();
+    let mut statements = TokenStream::new();
+
+    if let Some(domain) = &attributes.domain {
+        let domain = match domain {
+            Domain::TypeName => name.unraw().to_string(),
+            Domain::Custom(domain) => domain.clone(),
+        };
+
+        statements.extend(quote! {
+            #crate_path::Fingerprint::fingerprint(#domain, #hasher_arg);
+        });
+    }
+
+    if let Some(version) = attributes.version {
+        statements.extend(quote! {
+            #crate_path::Fingerprint::fingerprint(&#version, #hasher_arg);
+        });
+    }
+
+    statements
+}
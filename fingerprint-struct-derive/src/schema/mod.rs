@@ -0,0 +1,194 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{ext::IdentExt, Attribute, Data, DataEnum, DeriveInput, Fields};
+
+use crate::{
+    attributes::{
+        path_to_string, ContainerAttributes, Domain, FieldAttributes, TagKind, UnionMode,
+    },
+    enums::{discriminant::get_int_repr, get_tag_statement, get_tags},
+    fields::get_hashed_fields,
+    generics::{get_generic_parameters, get_schema_where_bounds, GenericParamType},
+    utils::{hasher_arg, stack_arg},
+};
+
+pub fn expand_fingerprint_schema(input: DeriveInput) -> syn::Result<TokenStream> {
+    let DeriveInput {
+        ident,
+        data,
+        attrs,
+        generics,
+        ..
+    } = input;
+
+    let attributes = ContainerAttributes::parse(&attrs)?;
+    let hasher_arg = hasher_arg();
+    let stack_arg = stack_arg();
+    let crate_path = attributes.crate_path();
+
+    let where_bounds = get_schema_where_bounds(&generics, &crate_path);
+    let generic_params_impl = get_generic_parameters(&generics, GenericParamType::Impl);
+    let generic_params_type = get_generic_parameters(&generics, GenericParamType::Type);
+
+    let (kind, body) = match data {
+        Data::Struct(data) => ("struct", get_fields_schema(&data.fields, &attributes)?),
+        Data::Enum(data) => ("enum", get_enum_schema(data, &attrs, &attributes)?),
+        Data::Union(data) => {
+            let mode = match &attributes.union_mode {
+                Some(UnionMode::Discriminant { .. }) => "discriminant",
+                Some(UnionMode::Bytes { .. }) => "bytes",
+                None => "",
+            };
+            let fields = get_fields_schema(&Fields::Named(data.fields), &attributes)?;
+
+            (
+                "union",
+                quote! {
+                    #crate_path::Fingerprint::fingerprint(#mode, #hasher_arg);
+                    #fields
+                },
+            )
+        }
+    };
+
+    let header = get_header(kind, &ident, &attributes);
+
+    Ok(quote! {
+        impl <#generic_params_impl> #crate_path::FingerprintSchema for #ident <#generic_params_type> where #where_bounds {
+            #[allow(unused_variables)] // In case there are no fields
+            fn fingerprint_schema<U: #crate_path::digest::Update>(#hasher_arg: &mut U, #stack_arg: #crate_path::SchemaStack<'_>) {
+                #header
+                #body
+            }
+        }
+    })
+}
+
+/// Generates statements that update the hasher with the kind and name of the type, and the
+/// container attributes that affect the hash of the whole type.
+fn get_header(kind: &str, ident: &Ident, attributes: &ContainerAttributes) -> TokenStream {
+    let hasher_arg = hasher_arg();
+    let crate_path = attributes.crate_path();
+
+    // Remote types are named after the type they mirror
+    let name = match attributes.remote.as_ref().and_then(|r| r.segments.last()) {
+        Some(segment) => segment.ident.unraw().to_string(),
+        None => ident.unraw().to_string(),
+    };
+
+    let domain = match &attributes.domain {
+        Some(Domain::TypeName) => quote!(::core::option::Option::<&str>::Some(#name)),
+        Some(Domain::Custom(domain)) => quote!(::core::option::Option::<&str>::Some(#domain)),
+        None => quote!(::core::option::Option::<&str>::None),
+    };
+
+    let version = match attributes.version {
+        Some(version) => quote!(::core::option::Option::<u32>::Some(#version)),
+        None => quote!(::core::option::Option::<u32>::None),
+    };
+
+    let named_fields = attributes.named_fields.is_some();
+
+    quote! {
+        #crate_path::Fingerprint::fingerprint(#kind, #hasher_arg);
+        #crate_path::Fingerprint::fingerprint(#name, #hasher_arg);
+        #crate_path::Fingerprint::fingerprint(&#domain, #hasher_arg);
+        #crate_path::Fingerprint::fingerprint(&#version, #hasher_arg);
+        #crate_path::Fingerprint::fingerprint(&#named_fields, #hasher_arg);
+    }
+}
+
+/// Generates statements that update the hasher with the way each variant is tagged, followed by
+/// the tag, name and fields of each variant.
+fn get_enum_schema(
+    data: DataEnum,
+    attrs: &[Attribute],
+    attributes: &ContainerAttributes,
+) -> syn::Result<TokenStream> {
+    let hasher_arg = hasher_arg();
+    let crate_path = attributes.crate_path();
+
+    let int_repr = get_int_repr(attrs)?;
+    let tag_kind = match attributes.tag.as_ref().map(|tag| tag.kind) {
+        Some(TagKind::Name) => "name".to_owned(),
+        _ => int_repr.to_string(),
+    };
+
+    let count = data.variants.len();
+    let mut statements = quote! {
+        #crate_path::Fingerprint::fingerprint(#tag_kind, #hasher_arg);
+        #crate_path::Fingerprint::fingerprint(&#count, #hasher_arg);
+    };
+
+    for (variant, tag) in get_tags(data.variants, attributes)? {
+        let name = variant.ident.unraw().to_string();
+        let tag = get_tag_statement(&tag, &int_repr, &crate_path);
+        let fields = get_fields_schema(&variant.fields, attributes)?;
+
+        statements.extend(quote! {
+            #crate_path::Fingerprint::fingerprint(#name, #hasher_arg);
+            #tag
+            #fields
+        });
+    }
+
+    Ok(statements)
+}
+
+/// Generates statements that update the hasher with the number of hashed fields, followed by the
+/// name and description of each field in the order in which they are hashed.
+fn get_fields_schema(fields: &Fields, container: &ContainerAttributes) -> syn::Result<TokenStream> {
+    let hasher_arg = hasher_arg();
+    let stack_arg = stack_arg();
+    let crate_path = container.crate_path();
+
+    let attributes = fields
+        .iter()
+        .map(|field| FieldAttributes::parse(&field.attrs))
+        .collect::<syn::Result<Vec<_>>>()?;
+    let fields = get_hashed_fields(fields, &attributes, container)?;
+
+    let count = fields.len();
+    let mut statements = quote! {
+        #crate_path::Fingerprint::fingerprint(&#count, #hasher_arg);
+    };
+
+    for field in fields {
+        let name = field.name;
+        let conditional = field.attributes.skip_if_default || field.attributes.skip_if.is_some();
+
+        let description = match &field.attributes.with {
+            Some(with) => {
+                let with = path_to_string(with);
+
+                quote! {
+                    #crate_path::Fingerprint::fingerprint(&1u8, #hasher_arg);
+                    #crate_path::Fingerprint::fingerprint(#with, #hasher_arg);
+                }
+            }
+            None => {
+                let ty = field.attributes.as_type.as_ref().unwrap_or(&field.field.ty);
+                let ty = match field.attributes.canonical_float {
+                    // Canonical fields are described like the wrapper that hashes them the same way
+                    Some(normalize_zero) => {
+                        quote!(#crate_path::CanonicalFloat<#ty, #normalize_zero>)
+                    }
+                    None => quote!(#ty),
+                };
+
+                quote! {
+                    #crate_path::Fingerprint::fingerprint(&0u8, #hasher_arg);
+                    #crate_path::SchemaStack::fingerprint::<#ty, _>(#stack_arg, #hasher_arg);
+                }
+            }
+        };
+
+        statements.extend(quote! {
+            #crate_path::Fingerprint::fingerprint(#name, #hasher_arg);
+            #crate_path::Fingerprint::fingerprint(&#conditional, #hasher_arg);
+            #description
+        });
+    }
+
+    Ok(statements)
+}
@@ -1,38 +1,67 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{DataStruct, Fields, Index};
+use syn::{DataStruct, Error, Fields, Index};
 
-use crate::{hasher_arg, utils::get_field_names};
+use crate::{
+    attributes::{ContainerAttributes, FieldAttributes},
+    fields::{
+        check_not_union_fields, get_field_binding, get_field_type_checks, get_fields_body,
+        get_fields_pattern,
+    },
+    utils::{remote_pattern_path, value_arg},
+};
 
-pub fn get_struct_body(data: DataStruct) -> TokenStream {
+pub fn get_struct_body(
+    data: DataStruct,
+    attributes: &ContainerAttributes,
+) -> syn::Result<TokenStream> {
     let DataStruct { fields, .. } = data;
 
-    let hasher_arg = hasher_arg();
+    if !matches!(fields, Fields::Named(_)) {
+        let path = attributes
+            .named_fields
+            .as_ref()
+            .or(attributes.sorted_fields.as_ref());
 
-    match fields {
-        Fields::Named(fields) => {
-            let idents = get_field_names(&fields);
-
-            let statements = idents.map(|ident| {
-                quote! {
-                    self.#ident.fingerprint(#hasher_arg);
-                }
-            });
-
-            statements.collect()
+        if let Some(path) = path {
+            return Err(Error::new_spanned(
+                path,
+                "fingerprint attributes `named_fields` and `sorted_fields` require named fields",
+            ));
         }
-        Fields::Unnamed(fields) => {
-            let numbers = 0..fields.unnamed.len();
-            let numbers = numbers.into_iter().map(Index::from);
+    }
 
-            let statements = numbers.map(|num| {
-                quote! {
-                    self.#num.fingerprint(#hasher_arg);
-                }
-            });
+    let field_attributes = fields
+        .iter()
+        .map(|field| FieldAttributes::parse(&field.attrs))
+        .collect::<syn::Result<Vec<_>>>()?;
+    check_not_union_fields(&field_attributes)?;
 
-            statements.collect()
-        }
-        Fields::Unit => TokenStream::default(),
+    if let Some(remote) = &attributes.remote {
+        // All fields are bound to check that they match the definition of the remote type
+        let path = remote_pattern_path(remote);
+        let pattern = get_fields_pattern(&fields, &field_attributes, true)?;
+        let type_checks = get_field_type_checks(&fields);
+        let body = get_fields_body(&fields, &field_attributes, attributes, get_field_binding)?;
+        let value_arg = value_arg();
+
+        return Ok(quote! {
+            let #path #pattern = #value_arg;
+            #type_checks
+            #body
+        });
     }
+
+    get_fields_body(
+        &fields,
+        &field_attributes,
+        attributes,
+        |index, field| match &field.ident {
+            Some(ident) => quote!(&self.#ident),
+            None => {
+                let index = Index::from(index);
+                quote!(&self.#index)
+            }
+        },
+    )
 }
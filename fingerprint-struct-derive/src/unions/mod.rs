@@ -0,0 +1,158 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{punctuated::Punctuated, DataUnion, Error, Fields, FieldsNamed, Generics};
+
+use crate::{
+    attributes::{ContainerAttributes, FieldAttributes, UnionMode},
+    fields::get_fields_body,
+    utils::{get_field_name, hasher_arg},
+};
+
+pub fn get_union_body(
+    data: DataUnion,
+    name: &Ident,
+    generics: &Generics,
+    attributes: &ContainerAttributes,
+) -> syn::Result<TokenStream> {
+    if let Some(remote) = &attributes.remote {
+        return Err(Error::new_spanned(
+            remote,
+            "fingerprint attribute `remote` cannot be used on unions",
+        ));
+    }
+
+    match &attributes.union_mode {
+        Some(UnionMode::Discriminant { expr, .. }) => get_discriminant_body(data, expr, attributes),
+        Some(UnionMode::Bytes { .. }) => get_bytes_body(data, name, generics, attributes),
+        None => Err(Error::new_spanned(
+            name,
+            "deriving Fingerprint for a union requires \
+             `#[fingerprint(unsafe_discriminant = \"...\")]` or `#[fingerprint(bytes)]`",
+        )),
+    }
+}
+
+/// Generates a match on the discriminant expression, with an arm for each field.
+///
+/// The index of the active field is hashed like the discriminant of an enum variant, followed by
+/// the field itself, as if it was the only field of the variant.
+fn get_discriminant_body(
+    data: DataUnion,
+    expr: &TokenStream,
+    attributes: &ContainerAttributes,
+) -> syn::Result<TokenStream> {
+    let DataUnion { fields, .. } = data;
+
+    let hasher_arg = hasher_arg();
+    let crate_path = attributes.crate_path();
+    let mut arms = TokenStream::new();
+
+    for (index, field) in fields.named.into_iter().enumerate() {
+        let field_attributes = FieldAttributes::parse(&field.attrs)?;
+        let ident = get_field_name(&field)?;
+
+        let pattern = match &field_attributes.when {
+            Some(pattern) => pattern.clone(),
+            // Skipped fields can't be active, so they don't need a pattern
+            None if field_attributes.skip => continue,
+            None => {
+                return Err(Error::new_spanned(
+                    ident,
+                    "fields of a union with `#[fingerprint(unsafe_discriminant = \"...\")]` \
+                     require `#[fingerprint(when = \"...\")]`",
+                ))
+            }
+        };
+
+        let mut named = Punctuated::new();
+        named.push(field);
+
+        let fields = Fields::Named(FieldsNamed {
+            brace_token: fields.brace_token,
+            named,
+        });
+
+        // SAFETY: The discriminant expression selects the active field. Its correctness is
+        // asserted by the user with the `unsafe_discriminant` attribute.
+        let body = get_fields_body(
+            &fields,
+            &[field_attributes],
+            attributes,
+            |_, _| quote!(unsafe { &self.#ident }),
+        )?;
+        let index = index as isize;
+
+        arms.extend(quote! {
+            #pattern => {
+                #crate_path::Fingerprint::fingerprint(&#index, #hasher_arg);
+                #body
+            }
+        });
+    }
+
+    Ok(quote! {
+        match #expr {
+            #arms
+        }
+    })
+}
+
+/// Generates statements that hash the bytes of the union, which must be fully initialized no
+/// matter which field is active.
+///
+/// This is ensured by requiring that all fields have no padding bytes and the same size as the
+/// union. The sizes are compared at compile time, at the definition of the union if it isn't
+/// generic, or otherwise when the implementation is used.
+///
+/// The bytes are hashed in little-endian byte order, as if the first field was active.
+fn get_bytes_body(
+    data: DataUnion,
+    name: &Ident,
+    generics: &Generics,
+    attributes: &ContainerAttributes,
+) -> syn::Result<TokenStream> {
+    let DataUnion { fields, .. } = data;
+
+    for field in fields.named.iter() {
+        if let Some(attr) = field.attrs.iter().find(|a| a.path.is_ident("fingerprint")) {
+            return Err(Error::new_spanned(
+                attr,
+                "fingerprint field attributes cannot be used with `#[fingerprint(bytes)]`",
+            ));
+        }
+    }
+
+    let hasher_arg = hasher_arg();
+    let crate_path = attributes.crate_path();
+    let types: Vec<_> = fields.named.iter().map(|field| &field.ty).collect();
+    // Unions have at least one field
+    let first = types[0];
+
+    let checks = match generics.params.is_empty() {
+        true => quote! {
+            #(
+                const _: () = #crate_path::__private::AssertSameSize::<#types, #name>::OK;
+            )*
+        },
+        false => quote! {
+            #(
+                let () = #crate_path::__private::AssertSameSize::<#types, Self>::OK;
+            )*
+        },
+    };
+
+    Ok(quote! {
+        #checks
+
+        // SAFETY: All fields have the same size as the union and no padding bytes, so all bytes
+        // are initialized regardless of which field is active.
+        let bytes = unsafe {
+            ::core::slice::from_raw_parts(
+                self as *const Self as *const ::core::primitive::u8,
+                ::core::mem::size_of::<Self>(),
+            )
+        };
+
+        #crate_path::__private::update_le::<#first, _>(#hasher_arg, bytes);
+    })
+}
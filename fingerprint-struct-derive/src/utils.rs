@@ -1,18 +1,16 @@
-use proc_macro2::{Ident, Span};
-use syn::{FieldsNamed, FieldsUnnamed};
-
-pub fn get_field_names(fields: &FieldsNamed) -> impl Iterator<Item = Ident> + '_ {
-    fields
-        .named
-        .iter()
-        .map(|field| field.ident.clone().expect("named fields to have names"))
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::ToTokens;
+use syn::{Error, Field, Path, PathArguments};
+
+pub fn get_field_name(field: &Field) -> syn::Result<Ident> {
+    match &field.ident {
+        Some(ident) => Ok(ident.clone()),
+        None => Err(Error::new_spanned(field, "expected a named field")),
+    }
 }
 
-pub fn get_unnamed_field_names(fields: &FieldsUnnamed) -> impl Iterator<Item = Ident> + '_ {
-    let numbers = 0..fields.unnamed.len();
-    numbers
-        .into_iter()
-        .map(|n| Ident::new(&format!("f{n}"), Span::mixed_site()))
+pub fn get_unnamed_field_name(index: usize) -> Ident {
+    Ident::new(&format!("f{index}"), Span::mixed_site())
 }
 
 pub fn hasher_arg() -> Ident {
@@ -21,3 +19,35 @@ pub fn hasher_arg() -> Ident {
         Span::mixed_site(),
     )
 }
+
+pub fn stack_arg() -> Ident {
+    Ident::new(
+        "__internal_fingerprint_struct_derive_implementation_stack_argument",
+        Span::mixed_site(),
+    )
+}
+
+pub fn input_arg() -> Ident {
+    Ident::new(
+        "__internal_fingerprint_struct_derive_implementation_input_argument",
+        Span::mixed_site(),
+    )
+}
+
+pub fn value_arg() -> Ident {
+    Ident::new(
+        "__internal_fingerprint_struct_derive_implementation_value_argument",
+        Span::mixed_site(),
+    )
+}
+
+/// Gets the path of a remote type without generic arguments, so it can be used in patterns.
+pub fn remote_pattern_path(remote: &Path) -> TokenStream {
+    let mut path = remote.clone();
+
+    if let Some(segment) = path.segments.last_mut() {
+        segment.arguments = PathArguments::None;
+    }
+
+    path.to_token_stream()
+}
@@ -0,0 +1,83 @@
+use blake2::Blake2b512;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use fingerprint_struct::{fingerprint, BufferedUpdate, Fingerprint};
+use sha2::Sha256;
+
+// The same shapes as in `examples/derive.rs`
+#[derive(Fingerprint)]
+struct Color(u8, u8, u8);
+
+#[derive(Fingerprint)]
+struct Point<T>(T, T);
+
+#[derive(Fingerprint)]
+enum Shape {
+    Background(Color),
+    Circle {
+        center: Point<f64>,
+        radius: f64,
+        color: Color,
+    },
+    Polygon {
+        points: Vec<Point<f64>>,
+        color: Color,
+    },
+    Empty,
+}
+
+fn payload() -> Vec<Shape> {
+    (0..1000)
+        .map(|i| match i % 4 {
+            0 => Shape::Background(Color(0xff, i as u8, 0xff)),
+            1 => Shape::Circle {
+                center: Point(20.0, i as f64),
+                radius: 12.5,
+                color: Color(0xff, 0x00, 0xff),
+            },
+            2 => Shape::Polygon {
+                points: vec![
+                    Point(10.0, 0.0),
+                    Point(0.0, 10.0),
+                    Point(-10.0, i as f64),
+                    Point(0.0, -10.0),
+                ],
+                color: Color(0x55, 0xff, 0x00),
+            },
+            _ => Shape::Empty,
+        })
+        .collect()
+}
+
+fn bench_buffered(c: &mut Criterion) {
+    let payload = payload();
+
+    let mut group = c.benchmark_group("sha256");
+    group.bench_function("unbuffered", |b| {
+        b.iter(|| fingerprint::<Sha256>(black_box(&payload)))
+    });
+    group.bench_function("buffered 64", |b| {
+        b.iter(|| fingerprint::<BufferedUpdate<Sha256, 64>>(black_box(&payload)))
+    });
+    group.bench_function("buffered 256", |b| {
+        b.iter(|| fingerprint::<BufferedUpdate<Sha256, 256>>(black_box(&payload)))
+    });
+    group.bench_function("buffered 1024", |b| {
+        b.iter(|| fingerprint::<BufferedUpdate<Sha256>>(black_box(&payload)))
+    });
+    group.bench_function("buffered 4096", |b| {
+        b.iter(|| fingerprint::<BufferedUpdate<Sha256, 4096>>(black_box(&payload)))
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("blake2b512");
+    group.bench_function("unbuffered", |b| {
+        b.iter(|| fingerprint::<Blake2b512>(black_box(&payload)))
+    });
+    group.bench_function("buffered 1024", |b| {
+        b.iter(|| fingerprint::<BufferedUpdate<Blake2b512>>(black_box(&payload)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_buffered);
+criterion_main!(benches);
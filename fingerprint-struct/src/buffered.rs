@@ -0,0 +1,110 @@
+use digest::{FixedOutput, Output, OutputSizeUser, Reset, Update};
+
+/// An adapter which collects small writes into a buffer before passing them to a hasher.
+///
+/// Hashing a value usually results in many calls to [`Update::update`] with just a few bytes each.
+/// For some hashers, each call has a noticeable overhead, so collecting the bytes into a buffer of
+/// `N` bytes on the stack first can be faster. Writes that don't fit into the buffer are passed
+/// to the hasher directly. The hash is not affected by buffering.
+///
+/// Most hashers already buffer a single block internally, so the buffer should be several blocks
+/// long to make a difference. The default size is 1024 bytes, which performed best with SHA-256
+/// in the `buffered` benchmark. Whether buffering helps at all depends on the hasher: BLAKE2b,
+/// for example, is slightly slower with it, so it's not used by default.
+///
+/// The adapter implements [`FixedOutput`] if the hasher does, so it can be passed to
+/// [`fingerprint`](crate::fingerprint) and [`fingerprint_with`](crate::fingerprint_with).
+///
+/// The buffer is flushed by [`flush`](BufferedUpdate::flush),
+/// [`into_inner`](BufferedUpdate::into_inner) and when the hash is finalized.
+///
+/// # Examples
+/// ```
+/// use fingerprint_struct::{BufferedUpdate, Fingerprint};
+/// use sha2::{digest::FixedOutput, Sha256};
+///
+/// let mut hasher = BufferedUpdate::<Sha256, 128>::default();
+/// (1u8, 2u16, 3u32).fingerprint(&mut hasher);
+/// let hash = hasher.finalize_fixed();
+/// ```
+#[derive(Clone, Debug)]
+pub struct BufferedUpdate<U, const N: usize = 1024> {
+    inner: U,
+    buffer: [u8; N],
+    len: usize,
+}
+
+impl<U, const N: usize> BufferedUpdate<U, N> {
+    /// Creates a new adapter which passes the bytes to a hasher.
+    pub fn new(inner: U) -> Self {
+        BufferedUpdate {
+            inner,
+            buffer: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Gets a reference to the hasher.
+    ///
+    /// The bytes in the buffer haven't been passed to it yet.
+    pub fn get_ref(&self) -> &U {
+        &self.inner
+    }
+}
+
+impl<U: Update, const N: usize> BufferedUpdate<U, N> {
+    /// Passes all bytes in the buffer to the hasher.
+    pub fn flush(&mut self) {
+        if self.len > 0 {
+            self.inner.update(&self.buffer[..self.len]);
+            self.len = 0;
+        }
+    }
+
+    /// Flushes the buffer and returns the hasher.
+    pub fn into_inner(mut self) -> U {
+        self.flush();
+        self.inner
+    }
+}
+
+impl<U: Default, const N: usize> Default for BufferedUpdate<U, N> {
+    fn default() -> Self {
+        Self::new(U::default())
+    }
+}
+
+impl<U: Update, const N: usize> Update for BufferedUpdate<U, N> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        if data.len() > N - self.len {
+            self.flush();
+
+            if data.len() >= N {
+                self.inner.update(data);
+                return;
+            }
+        }
+
+        self.buffer[self.len..self.len + data.len()].copy_from_slice(data);
+        self.len += data.len();
+    }
+}
+
+impl<U: OutputSizeUser, const N: usize> OutputSizeUser for BufferedUpdate<U, N> {
+    type OutputSize = U::OutputSize;
+}
+
+impl<U: Update + FixedOutput, const N: usize> FixedOutput for BufferedUpdate<U, N> {
+    fn finalize_into(mut self, out: &mut Output<Self>) {
+        self.flush();
+        self.inner.finalize_into(out);
+    }
+}
+
+impl<U: Reset, const N: usize> Reset for BufferedUpdate<U, N> {
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.len = 0;
+    }
+}
@@ -0,0 +1,210 @@
+use digest::Update;
+
+use crate::{Fingerprint, FingerprintSchema, SchemaStack};
+
+/// A data structure whose cryptographic hash can be computed with canonical floating point
+/// numbers.
+///
+/// Values are hashed exactly like with [`Fingerprint`], except that all NaNs are hashed like the
+/// same canonical quiet NaN, regardless of their sign and payload. If `normalize_zero` is set,
+/// `-0.0` is also hashed like `0.0`.
+///
+/// Implementations are provided for floats, other primitives, strings and common containers, so
+/// floats nested in them are canonicalized too. It's usually used through [`CanonicalFloat`] or
+/// the `canonical_float` field attribute of the derive macro.
+pub trait FingerprintCanonical {
+    /// Use this value to update a hasher, canonicalizing all contained floats.
+    fn fingerprint_canonical<U: Update>(&self, hasher: &mut U, normalize_zero: bool);
+}
+
+/// A wrapper which hashes the floats contained in a value canonically.
+///
+/// All NaNs are hashed like the same canonical quiet NaN. If `NORMALIZE_ZERO` is `true`, `-0.0`
+/// is also hashed like `0.0`. All other values are hashed like the wrapped value.
+///
+/// # Examples
+/// ```
+/// use fingerprint_struct::{fingerprint, CanonicalFloat};
+/// use sha2::Sha256;
+///
+/// let a = fingerprint::<Sha256>(CanonicalFloat::<_>([f64::NAN, 1.0]));
+/// let b = fingerprint::<Sha256>(CanonicalFloat::<_>([-f64::NAN, 1.0]));
+/// assert_eq!(a, b);
+///
+/// let a = fingerprint::<Sha256>(CanonicalFloat::<_, true>((0.0f32, "zero")));
+/// let b = fingerprint::<Sha256>(CanonicalFloat::<_, true>((-0.0f32, "zero")));
+/// assert_eq!(a, b);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct CanonicalFloat<T, const NORMALIZE_ZERO: bool = false>(pub T);
+
+impl<T: FingerprintCanonical, const NORMALIZE_ZERO: bool> Fingerprint
+    for CanonicalFloat<T, NORMALIZE_ZERO>
+{
+    #[inline(always)]
+    fn fingerprint<U: Update>(&self, hasher: &mut U) {
+        self.0.fingerprint_canonical(hasher, NORMALIZE_ZERO);
+    }
+}
+
+macro_rules! impl_float {
+    ($type: ty, $bits: ty, $nan: literal) => {
+        impl FingerprintCanonical for $type {
+            #[inline]
+            fn fingerprint_canonical<U: Update>(&self, hasher: &mut U, normalize_zero: bool) {
+                if self.is_nan() {
+                    <$bits>::fingerprint(&$nan, hasher);
+                } else if normalize_zero && *self == 0.0 {
+                    <$type>::fingerprint(&0.0, hasher);
+                } else {
+                    self.fingerprint(hasher);
+                }
+            }
+        }
+    };
+}
+
+impl_float!(f32, u32, 0x7fc0_0000);
+impl_float!(f64, u64, 0x7ff8_0000_0000_0000);
+
+macro_rules! impl_through_fingerprint {
+    ($($type: ty),*) => {
+        $(
+            impl FingerprintCanonical for $type {
+                #[inline(always)]
+                fn fingerprint_canonical<U: Update>(&self, hasher: &mut U, _normalize_zero: bool) {
+                    self.fingerprint(hasher);
+                }
+            }
+        )*
+    };
+}
+
+impl_through_fingerprint!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_through_fingerprint!(bool, char, str);
+#[cfg(feature = "alloc")]
+impl_through_fingerprint!(alloc::string::String);
+
+macro_rules! impl_deref {
+    ($type: ty) => {
+        impl<T: FingerprintCanonical + ?Sized> FingerprintCanonical for $type {
+            #[inline(always)]
+            fn fingerprint_canonical<U: Update>(&self, hasher: &mut U, normalize_zero: bool) {
+                (**self).fingerprint_canonical(hasher, normalize_zero);
+            }
+        }
+    };
+}
+
+impl_deref!(&T);
+impl_deref!(&mut T);
+#[cfg(feature = "alloc")]
+impl_deref!(alloc::boxed::Box<T>);
+#[cfg(feature = "alloc")]
+impl_deref!(alloc::rc::Rc<T>);
+#[cfg(feature = "alloc")]
+impl_deref!(alloc::sync::Arc<T>);
+
+impl<T: FingerprintCanonical, const N: usize> FingerprintCanonical for [T; N] {
+    #[inline(always)]
+    fn fingerprint_canonical<U: Update>(&self, hasher: &mut U, normalize_zero: bool) {
+        for i in self {
+            i.fingerprint_canonical(hasher, normalize_zero);
+        }
+    }
+}
+
+macro_rules! impl_tuple {
+    ($($num: tt: $name: ident)*) => {
+        impl<$($name: FingerprintCanonical),*> FingerprintCanonical for ($($name,)*) {
+            #[inline(always)]
+            #[allow(unused_variables)] // In case of the empty tuple
+            fn fingerprint_canonical<U: Update>(&self, hasher: &mut U, normalize_zero: bool) {
+                $(
+                    self.$num.fingerprint_canonical(hasher, normalize_zero);
+                )*
+            }
+        }
+    };
+}
+
+impl_tuple!();
+impl_tuple!(0: T0);
+impl_tuple!(0: T0 1: T1);
+impl_tuple!(0: T0 1: T1 2: T2);
+impl_tuple!(0: T0 1: T1 2: T2 3: T3);
+impl_tuple!(0: T0 1: T1 2: T2 3: T3 4: T4);
+impl_tuple!(0: T0 1: T1 2: T2 3: T3 4: T4 5: T5);
+impl_tuple!(0: T0 1: T1 2: T2 3: T3 4: T4 5: T5 6: T6);
+impl_tuple!(0: T0 1: T1 2: T2 3: T3 4: T4 5: T5 6: T6 7: T7);
+impl_tuple!(0: T0 1: T1 2: T2 3: T3 4: T4 5: T5 6: T6 7: T7 8: T8);
+impl_tuple!(0: T0 1: T1 2: T2 3: T3 4: T4 5: T5 6: T6 7: T7 8: T8 9: T9);
+impl_tuple!(0: T0 1: T1 2: T2 3: T3 4: T4 5: T5 6: T6 7: T7 8: T8 9: T9 10: T10);
+impl_tuple!(0: T0 1: T1 2: T2 3: T3 4: T4 5: T5 6: T6 7: T7 8: T8 9: T9 10: T10 11: T11);
+impl_tuple!(0: T0 1: T1 2: T2 3: T3 4: T4 5: T5 6: T6 7: T7 8: T8 9: T9 10: T10 11: T11 12: T12);
+impl_tuple!(0: T0 1: T1 2: T2 3: T3 4: T4 5: T5 6: T6 7: T7 8: T8 9: T9 10: T10 11: T11 12: T12 13: T13);
+impl_tuple!(0: T0 1: T1 2: T2 3: T3 4: T4 5: T5 6: T6 7: T7 8: T8 9: T9 10: T10 11: T11 12: T12 13: T13 14: T14);
+impl_tuple!(0: T0 1: T1 2: T2 3: T3 4: T4 5: T5 6: T6 7: T7 8: T8 9: T9 10: T10 11: T11 12: T12 13: T13 14: T14 15: T15);
+
+impl<T: FingerprintCanonical> FingerprintCanonical for Option<T> {
+    #[inline]
+    fn fingerprint_canonical<U: Update>(&self, hasher: &mut U, normalize_zero: bool) {
+        match self {
+            Some(value) => {
+                0u8.fingerprint(hasher);
+                value.fingerprint_canonical(hasher, normalize_zero);
+            }
+            None => 1u8.fingerprint(hasher),
+        }
+    }
+}
+
+impl<T: FingerprintCanonical, E: FingerprintCanonical> FingerprintCanonical for Result<T, E> {
+    #[inline]
+    fn fingerprint_canonical<U: Update>(&self, hasher: &mut U, normalize_zero: bool) {
+        match self {
+            Ok(value) => {
+                0u8.fingerprint(hasher);
+                value.fingerprint_canonical(hasher, normalize_zero);
+            }
+            Err(value) => {
+                1u8.fingerprint(hasher);
+                value.fingerprint_canonical(hasher, normalize_zero);
+            }
+        }
+    }
+}
+
+macro_rules! impl_ordered_seq {
+    ($type: ty) => {
+        impl<T: FingerprintCanonical> FingerprintCanonical for $type {
+            #[inline]
+            fn fingerprint_canonical<U: Update>(&self, hasher: &mut U, normalize_zero: bool) {
+                self.len().fingerprint(hasher);
+
+                for element in self.iter() {
+                    element.fingerprint_canonical(hasher, normalize_zero);
+                }
+            }
+        }
+    };
+}
+
+impl_ordered_seq!([T]);
+#[cfg(feature = "alloc")]
+impl_ordered_seq!(alloc::vec::Vec<T>);
+#[cfg(feature = "alloc")]
+impl_ordered_seq!(alloc::collections::VecDeque<T>);
+#[cfg(feature = "alloc")]
+impl_ordered_seq!(alloc::collections::LinkedList<T>);
+
+impl<T: FingerprintSchema, const NORMALIZE_ZERO: bool> FingerprintSchema
+    for CanonicalFloat<T, NORMALIZE_ZERO>
+{
+    #[inline]
+    fn fingerprint_schema<U: Update>(hasher: &mut U, stack: SchemaStack<'_>) {
+        "CanonicalFloat".fingerprint(hasher);
+        NORMALIZE_ZERO.fingerprint(hasher);
+        stack.fingerprint::<T, U>(hasher);
+    }
+}
@@ -0,0 +1,639 @@
+use core::fmt;
+
+use digest::Update;
+
+use crate::Fingerprint;
+
+/// A data structure which can be reconstructed from the bytes it updates a hasher with.
+///
+/// The encoding used by [`Fingerprint`] is unambiguous, so it can also be used as a deterministic
+/// serialization format. Decoding the bytes produced by [`encode_into`](crate::encode_into)
+/// results in an equal value. Input that can't be produced by encoding a value, like an unsorted
+/// set or a [`usize`] encoded with unnecessary digits, is rejected.
+///
+/// Implementations are provided for all owned types from [`std`] that implement [`Fingerprint`],
+/// and can be derived using `#[derive(FingerprintDecode)]`.
+pub trait FingerprintDecode: Sized {
+    /// Decode a value from the start of the input, advancing the input past the decoded bytes.
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError>;
+}
+
+/// An error returned when decoding a value fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DecodeError {
+    /// The input ended before the whole value was decoded.
+    UnexpectedEnd,
+    /// The input isn't the encoding of any value, for example because it contains an unknown enum
+    /// tag or invalid UTF-8.
+    InvalidValue,
+    /// The input continues after the end of the value.
+    TrailingBytes,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            DecodeError::UnexpectedEnd => "unexpected end of input",
+            DecodeError::InvalidValue => "input isn't the encoding of a valid value",
+            DecodeError::TrailingBytes => "input continues after the end of the value",
+        };
+
+        f.write_str(message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+/// Decode a value from its encoding, which must span the whole slice.
+///
+/// # Examples
+/// ```
+/// use fingerprint_struct::{decode_from_slice, encode_into, DecodeError};
+///
+/// let mut bytes = Vec::new();
+/// encode_into(&(1u16, 'a'), &mut bytes);
+/// assert_eq!(decode_from_slice(&bytes), Ok((1u16, 'a')));
+///
+/// assert_eq!(decode_from_slice::<u16>(&[1]), Err(DecodeError::UnexpectedEnd));
+/// assert_eq!(decode_from_slice::<u8>(&[1, 2]), Err(DecodeError::TrailingBytes));
+/// ```
+pub fn decode_from_slice<T: FingerprintDecode>(mut bytes: &[u8]) -> Result<T, DecodeError> {
+    let value = T::decode(&mut bytes)?;
+
+    match bytes.is_empty() {
+        true => Ok(value),
+        false => Err(DecodeError::TrailingBytes),
+    }
+}
+
+/// Removes `len` bytes from the start of the input and returns them.
+fn take<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], DecodeError> {
+    if input.len() < len {
+        return Err(DecodeError::UnexpectedEnd);
+    }
+
+    let (bytes, rest) = input.split_at(len);
+    *input = rest;
+    Ok(bytes)
+}
+
+/// Checks that the input starts with the encoding of `expected`, and if so, advances the input
+/// past it. Otherwise, the input is left unchanged.
+pub fn decode_expected<T: Fingerprint + ?Sized>(
+    input: &mut &[u8],
+    expected: &T,
+) -> Result<(), DecodeError> {
+    let mut matcher = ExpectUpdate {
+        input,
+        result: Ok(()),
+    };
+    expected.fingerprint(&mut matcher);
+
+    matcher.result?;
+    *input = matcher.input;
+    Ok(())
+}
+
+/// Decodes a string without copying it.
+pub fn decode_str<'a>(input: &mut &'a [u8]) -> Result<&'a str, DecodeError> {
+    let len = usize::decode(input)?;
+    let bytes = take(input, len)?;
+
+    core::str::from_utf8(bytes).map_err(|_| DecodeError::InvalidValue)
+}
+
+struct ExpectUpdate<'a> {
+    input: &'a [u8],
+    result: Result<(), DecodeError>,
+}
+
+impl Update for ExpectUpdate<'_> {
+    fn update(&mut self, data: &[u8]) {
+        if self.result.is_err() {
+            return;
+        }
+
+        let len = data.len().min(self.input.len());
+
+        self.result = if self.input[..len] != data[..len] {
+            Err(DecodeError::InvalidValue)
+        } else if len < data.len() {
+            Err(DecodeError::UnexpectedEnd)
+        } else {
+            self.input = &self.input[len..];
+            Ok(())
+        };
+    }
+}
+
+macro_rules! impl_primitive {
+    ($type: ty) => {
+        impl FingerprintDecode for $type {
+            #[inline]
+            fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+                let mut bytes = [0u8; core::mem::size_of::<$type>()];
+                bytes.copy_from_slice(take(input, core::mem::size_of::<$type>())?);
+                Ok(<$type>::from_le_bytes(bytes))
+            }
+        }
+    };
+}
+
+impl_primitive!(i8);
+impl_primitive!(i16);
+impl_primitive!(i32);
+impl_primitive!(i64);
+impl_primitive!(i128);
+impl_primitive!(u8);
+impl_primitive!(u16);
+impl_primitive!(u32);
+impl_primitive!(u64);
+impl_primitive!(u128);
+impl_primitive!(f32);
+impl_primitive!(f64);
+
+impl FingerprintDecode for bool {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        match u8::decode(input)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl FingerprintDecode for char {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        char::from_u32(u32::decode(input)?).ok_or(DecodeError::InvalidValue)
+    }
+}
+
+macro_rules! impl_through_try_from {
+    ($type: ty, $from: ty) => {
+        impl FingerprintDecode for $type {
+            #[inline]
+            fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+                <$type>::try_from(<$from>::decode(input)?).map_err(|_| DecodeError::InvalidValue)
+            }
+        }
+    };
+}
+
+impl_through_try_from!(core::num::NonZeroI8, i8);
+impl_through_try_from!(core::num::NonZeroI16, i16);
+impl_through_try_from!(core::num::NonZeroI32, i32);
+impl_through_try_from!(core::num::NonZeroI64, i64);
+impl_through_try_from!(core::num::NonZeroI128, i128);
+impl_through_try_from!(core::num::NonZeroIsize, isize);
+impl_through_try_from!(core::num::NonZeroU8, u8);
+impl_through_try_from!(core::num::NonZeroU16, u16);
+impl_through_try_from!(core::num::NonZeroU32, u32);
+impl_through_try_from!(core::num::NonZeroU64, u64);
+impl_through_try_from!(core::num::NonZeroU128, u128);
+impl_through_try_from!(core::num::NonZeroUsize, usize);
+
+macro_rules! impl_through_new {
+    ($type: ty, $inner: ty) => {
+        impl FingerprintDecode for $type {
+            #[inline]
+            fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+                Ok(<$type>::new(<$inner>::decode(input)?))
+            }
+        }
+    };
+}
+
+impl_through_new!(core::sync::atomic::AtomicBool, bool);
+impl_through_new!(core::sync::atomic::AtomicI8, i8);
+impl_through_new!(core::sync::atomic::AtomicI16, i16);
+impl_through_new!(core::sync::atomic::AtomicI32, i32);
+impl_through_new!(core::sync::atomic::AtomicI64, i64);
+impl_through_new!(core::sync::atomic::AtomicIsize, isize);
+impl_through_new!(core::sync::atomic::AtomicU8, u8);
+impl_through_new!(core::sync::atomic::AtomicU16, u16);
+impl_through_new!(core::sync::atomic::AtomicU32, u32);
+impl_through_new!(core::sync::atomic::AtomicU64, u64);
+impl_through_new!(core::sync::atomic::AtomicUsize, usize);
+
+impl FingerprintDecode for usize {
+    // Decodes the variable size integer produced by the Fingerprint implementation. Digits that
+    // don't fit into a usize and trailing zero digits are rejected, so each value has exactly one
+    // valid encoding.
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        let mut value = 0usize;
+        let mut shift = 0;
+
+        loop {
+            let byte = u8::decode(input)?;
+            let digit = (byte & ((1 << 7) - 1)) as usize;
+
+            if shift >= usize::BITS || (digit << shift) >> shift != digit {
+                return Err(DecodeError::InvalidValue);
+            }
+
+            value |= digit << shift;
+            shift += 7;
+
+            if byte & (1 << 7) == 0 {
+                if digit == 0 && shift > 7 {
+                    return Err(DecodeError::InvalidValue);
+                }
+
+                return Ok(value);
+            }
+        }
+    }
+}
+
+impl FingerprintDecode for isize {
+    // Reverses the "zigzag" encoding, see the Fingerprint implementation.
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        let value = usize::decode(input)?;
+        Ok(((value >> 1) as isize) ^ -((value & 1) as isize))
+    }
+}
+
+impl<T: FingerprintDecode, const N: usize> FingerprintDecode for [T; N] {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        let mut error = None;
+        let values = [(); N].map(|()| match error {
+            Some(_) => None,
+            None => T::decode(input).map_err(|e| error = Some(e)).ok(),
+        });
+
+        match error {
+            Some(error) => Err(error),
+            None => Ok(values.map(|value| value.expect("all elements are decoded"))),
+        }
+    }
+}
+
+macro_rules! impl_tuple {
+    ($($name: ident)*) => {
+        impl<$($name: FingerprintDecode),*> FingerprintDecode for ($($name,)*) {
+            #[inline]
+            #[allow(unused_variables)] // In case of the empty tuple
+            fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+                Ok(($($name::decode(input)?,)*))
+            }
+        }
+    };
+}
+
+impl_tuple!();
+impl_tuple!(T0);
+impl_tuple!(T0 T1);
+impl_tuple!(T0 T1 T2);
+impl_tuple!(T0 T1 T2 T3);
+impl_tuple!(T0 T1 T2 T3 T4);
+impl_tuple!(T0 T1 T2 T3 T4 T5);
+impl_tuple!(T0 T1 T2 T3 T4 T5 T6);
+impl_tuple!(T0 T1 T2 T3 T4 T5 T6 T7);
+impl_tuple!(T0 T1 T2 T3 T4 T5 T6 T7 T8);
+impl_tuple!(T0 T1 T2 T3 T4 T5 T6 T7 T8 T9);
+impl_tuple!(T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10);
+impl_tuple!(T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11);
+impl_tuple!(T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12);
+impl_tuple!(T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 T13);
+impl_tuple!(T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 T13 T14);
+impl_tuple!(T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 T13 T14 T15);
+
+#[cfg(feature = "alloc")]
+impl FingerprintDecode for alloc::string::String {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        decode_str(input).map(Into::into)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FingerprintDecode for alloc::boxed::Box<str> {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        decode_str(input).map(Into::into)
+    }
+}
+
+#[cfg(feature = "std")]
+impl FingerprintDecode for std::ffi::CString {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        let len = input
+            .iter()
+            .position(|&byte| byte == 0)
+            .ok_or(DecodeError::UnexpectedEnd)?;
+        let bytes = take(input, len + 1)?;
+
+        std::ffi::CString::new(&bytes[..len]).map_err(|_| DecodeError::InvalidValue)
+    }
+}
+
+impl<T: FingerprintDecode> FingerprintDecode for Option<T> {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        match u8::decode(input)? {
+            0 => Ok(Some(T::decode(input)?)),
+            1 => Ok(None),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<T: FingerprintDecode, E: FingerprintDecode> FingerprintDecode for Result<T, E> {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        match u8::decode(input)? {
+            0 => Ok(Ok(T::decode(input)?)),
+            1 => Ok(Err(E::decode(input)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<T: ?Sized> FingerprintDecode for core::marker::PhantomData<T> {
+    #[inline]
+    fn decode(_input: &mut &[u8]) -> Result<Self, DecodeError> {
+        Ok(core::marker::PhantomData)
+    }
+}
+
+macro_rules! impl_wrapper {
+    ($type: ty, $new: expr) => {
+        impl<T: FingerprintDecode> FingerprintDecode for $type {
+            #[inline]
+            fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+                T::decode(input).map($new)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+impl_wrapper!(alloc::boxed::Box<T>, alloc::boxed::Box::new);
+#[cfg(feature = "alloc")]
+impl_wrapper!(alloc::rc::Rc<T>, alloc::rc::Rc::new);
+#[cfg(feature = "alloc")]
+impl_wrapper!(alloc::sync::Arc<T>, alloc::sync::Arc::new);
+impl_wrapper!(core::cell::Cell<T>, core::cell::Cell::new);
+impl_wrapper!(core::num::Wrapping<T>, core::num::Wrapping);
+impl_wrapper!(core::cmp::Reverse<T>, core::cmp::Reverse);
+
+#[cfg(feature = "alloc")]
+impl<T: FingerprintDecode + Clone> FingerprintDecode for alloc::borrow::Cow<'_, T> {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        T::decode(input).map(alloc::borrow::Cow::Owned)
+    }
+}
+
+/// Decodes the length of a sequence followed by its elements.
+#[cfg(feature = "alloc")]
+fn decode_elements<T: FingerprintDecode>(
+    input: &mut &[u8],
+) -> Result<alloc::vec::Vec<T>, DecodeError> {
+    let len = usize::decode(input)?;
+
+    // The length isn't trusted, as each element takes up at least one byte of the input,
+    // unless it's zero-sized
+    let mut elements = alloc::vec::Vec::with_capacity(len.min(input.len()));
+
+    for _ in 0..len {
+        elements.push(T::decode(input)?);
+    }
+
+    Ok(elements)
+}
+
+/// Checks that the elements are sorted, which is always the case for the encoding of an
+/// unordered collection.
+#[cfg(feature = "alloc")]
+fn check_sorted<T>(elements: &[T], ordered: impl Fn(&T, &T) -> bool) -> Result<(), DecodeError> {
+    match elements.windows(2).all(|pair| ordered(&pair[0], &pair[1])) {
+        true => Ok(()),
+        false => Err(DecodeError::InvalidValue),
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: FingerprintDecode> FingerprintDecode for alloc::vec::Vec<T> {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        decode_elements(input)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: FingerprintDecode> FingerprintDecode for alloc::boxed::Box<[T]> {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        decode_elements(input).map(alloc::vec::Vec::into_boxed_slice)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: FingerprintDecode> FingerprintDecode for alloc::collections::VecDeque<T> {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        decode_elements(input).map(Into::into)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: FingerprintDecode> FingerprintDecode for alloc::collections::LinkedList<T> {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        decode_elements(input).map(|elements| elements.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: FingerprintDecode + Ord> FingerprintDecode for alloc::collections::BTreeSet<T> {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        let elements = decode_elements(input)?;
+        check_sorted(&elements, |a, b| a < b)?;
+        Ok(elements.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: FingerprintDecode + Ord> FingerprintDecode for alloc::collections::BinaryHeap<T> {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        let elements = decode_elements(input)?;
+        check_sorted(&elements, |a, b| a <= b)?;
+        Ok(elements.into())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: FingerprintDecode + Ord + Eq + core::hash::Hash> FingerprintDecode
+    for std::collections::HashSet<T>
+{
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        let elements = decode_elements(input)?;
+        check_sorted(&elements, |a, b| a < b)?;
+        Ok(elements.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: FingerprintDecode + Ord, V: FingerprintDecode> FingerprintDecode
+    for alloc::collections::BTreeMap<K, V>
+{
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        let elements: alloc::vec::Vec<(K, V)> = decode_elements(input)?;
+        check_sorted(&elements, |a, b| a.0 < b.0)?;
+        Ok(elements.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: FingerprintDecode + Ord + Eq + core::hash::Hash, V: FingerprintDecode> FingerprintDecode
+    for std::collections::HashMap<K, V>
+{
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        let elements: alloc::vec::Vec<(K, V)> = decode_elements(input)?;
+        check_sorted(&elements, |a, b| a.0 < b.0)?;
+        Ok(elements.into_iter().collect())
+    }
+}
+
+impl<T: FingerprintDecode> FingerprintDecode for core::ops::Range<T> {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        let start = T::decode(input)?;
+        let end = T::decode(input)?;
+        Ok(start..end)
+    }
+}
+
+impl<T: FingerprintDecode> FingerprintDecode for core::ops::RangeInclusive<T> {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        let start = T::decode(input)?;
+        let end = T::decode(input)?;
+        Ok(start..=end)
+    }
+}
+
+impl<T: FingerprintDecode> FingerprintDecode for core::ops::Bound<T> {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        match u8::decode(input)? {
+            0 => Ok(core::ops::Bound::Included(T::decode(input)?)),
+            1 => Ok(core::ops::Bound::Excluded(T::decode(input)?)),
+            2 => Ok(core::ops::Bound::Unbounded),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl FingerprintDecode for core::time::Duration {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+        let nanos = u128::decode(input)?;
+        let secs = u64::try_from(nanos / NANOS_PER_SEC).map_err(|_| DecodeError::InvalidValue)?;
+
+        Ok(core::time::Duration::new(
+            secs,
+            (nanos % NANOS_PER_SEC) as u32,
+        ))
+    }
+}
+
+#[cfg(all(feature = "std", feature = "os"))]
+impl FingerprintDecode for std::time::SystemTime {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        let time = match u8::decode(input)? {
+            0 => std::time::UNIX_EPOCH.checked_add(core::time::Duration::decode(input)?),
+            1 => {
+                let duration = core::time::Duration::decode(input)?;
+
+                // The epoch itself is encoded as a zero duration after it
+                match duration.is_zero() {
+                    true => None,
+                    false => std::time::UNIX_EPOCH.checked_sub(duration),
+                }
+            }
+            _ => None,
+        };
+
+        time.ok_or(DecodeError::InvalidValue)
+    }
+}
+
+#[cfg(feature = "std")]
+impl FingerprintDecode for std::net::Ipv4Addr {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        <[u8; 4]>::decode(input).map(Into::into)
+    }
+}
+
+#[cfg(feature = "std")]
+impl FingerprintDecode for std::net::Ipv6Addr {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        <[u8; 16]>::decode(input).map(Into::into)
+    }
+}
+
+#[cfg(feature = "std")]
+impl FingerprintDecode for std::net::IpAddr {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        match u8::decode(input)? {
+            4 => Ok(std::net::IpAddr::V4(FingerprintDecode::decode(input)?)),
+            6 => Ok(std::net::IpAddr::V6(FingerprintDecode::decode(input)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl FingerprintDecode for std::net::SocketAddrV4 {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        let ip = FingerprintDecode::decode(input)?;
+        let port = FingerprintDecode::decode(input)?;
+        Ok(std::net::SocketAddrV4::new(ip, port))
+    }
+}
+
+#[cfg(feature = "std")]
+impl FingerprintDecode for std::net::SocketAddrV6 {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        let ip = FingerprintDecode::decode(input)?;
+        let port = FingerprintDecode::decode(input)?;
+        let flowinfo = FingerprintDecode::decode(input)?;
+        let scope_id = FingerprintDecode::decode(input)?;
+        Ok(std::net::SocketAddrV6::new(ip, port, flowinfo, scope_id))
+    }
+}
+
+#[cfg(feature = "std")]
+impl FingerprintDecode for std::net::SocketAddr {
+    #[inline]
+    fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        match u8::decode(input)? {
+            4 => Ok(std::net::SocketAddr::V4(FingerprintDecode::decode(input)?)),
+            6 => Ok(std::net::SocketAddr::V6(FingerprintDecode::decode(input)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
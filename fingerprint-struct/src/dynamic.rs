@@ -0,0 +1,60 @@
+use digest::Update;
+
+use crate::Fingerprint;
+
+/// An object-safe version of [`Fingerprint`], which allows hashing trait objects.
+///
+/// It's implemented for every type implementing [`Fingerprint`], and `dyn DynFingerprint` in turn
+/// implements [`Fingerprint`], so boxed trait objects and collections of them can be hashed like
+/// any other value. The hash of a trait object is the same as the hash of the underlying value.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// use blake2::Blake2b512;
+/// use fingerprint_struct::{fingerprint, DynFingerprint};
+///
+/// let components: Vec<Box<dyn DynFingerprint>> = vec![Box::new(42u32), Box::new("plugin")];
+/// let hash = fingerprint::<Blake2b512>(&components);
+///
+/// assert_eq!(hash, fingerprint::<Blake2b512>((2usize, 42u32, "plugin")));
+/// # }
+/// ```
+pub trait DynFingerprint {
+    /// Use this value to update a hasher.
+    fn dyn_fingerprint(&self, hasher: &mut dyn Update);
+}
+
+impl<T: Fingerprint + ?Sized> DynFingerprint for T {
+    #[inline]
+    fn dyn_fingerprint(&self, hasher: &mut dyn Update) {
+        self.fingerprint(&mut DynUpdate(hasher));
+    }
+}
+
+/// Passes bytes to a hasher behind a trait object.
+struct DynUpdate<'a>(&'a mut dyn Update);
+
+impl Update for DynUpdate<'_> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+}
+
+macro_rules! impl_dyn {
+    ($type: ty) => {
+        impl Fingerprint for $type {
+            #[inline]
+            fn fingerprint<U: Update>(&self, hasher: &mut U) {
+                self.dyn_fingerprint(hasher);
+            }
+        }
+    };
+}
+
+impl_dyn!(dyn DynFingerprint);
+impl_dyn!(dyn DynFingerprint + Send);
+impl_dyn!(dyn DynFingerprint + Sync);
+impl_dyn!(dyn DynFingerprint + Send + Sync);
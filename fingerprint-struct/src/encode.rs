@@ -0,0 +1,67 @@
+use digest::Update;
+
+use crate::Fingerprint;
+
+/// Get the bytes a hasher is updated with when hashing a value.
+///
+/// Hashing these bytes directly results in the same hash as hashing the value.
+///
+/// # Examples
+/// ```
+/// use fingerprint_struct::encode_to_vec;
+///
+/// assert_eq!(encode_to_vec(&(1u16, "ab")), [1, 0, 2, b'a', b'b']);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_to_vec<T: Fingerprint + ?Sized>(value: &T) -> alloc::vec::Vec<u8> {
+    let mut result = alloc::vec::Vec::new();
+    encode_into(value, &mut result);
+    result
+}
+
+/// Append the bytes a hasher is updated with when hashing a value to a collection.
+///
+/// # Examples
+/// ```
+/// use fingerprint_struct::encode_into;
+///
+/// let mut bytes = vec![0xff];
+/// encode_into(&Some(42u8), &mut bytes);
+/// assert_eq!(bytes, [0xff, 0, 42]);
+/// ```
+pub fn encode_into<T: Fingerprint + ?Sized, E: Extend<u8>>(value: &T, out: &mut E) {
+    value.fingerprint(&mut ExtendUpdate(out));
+}
+
+/// Count the bytes a hasher is updated with when hashing a value, without allocating.
+///
+/// # Examples
+/// ```
+/// use fingerprint_struct::encoded_len;
+///
+/// assert_eq!(encoded_len(&[0u32; 4]), 16);
+/// assert_eq!(encoded_len("abc"), 4);
+/// ```
+pub fn encoded_len<T: Fingerprint + ?Sized>(value: &T) -> u64 {
+    let mut counter = CountingUpdate(0);
+    value.fingerprint(&mut counter);
+    counter.0
+}
+
+struct ExtendUpdate<'a, E>(&'a mut E);
+
+impl<E: Extend<u8>> Update for ExtendUpdate<'_, E> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.0.extend(data.iter().copied());
+    }
+}
+
+struct CountingUpdate(u64);
+
+impl Update for CountingUpdate {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.0 += data.len() as u64;
+    }
+}
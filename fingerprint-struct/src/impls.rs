@@ -61,6 +61,26 @@ impl<T: Fingerprint + Copy> Fingerprint for core::cell::Cell<T> {
     }
 }
 
+/// Updates the hasher with the memory representation of all values at once.
+///
+/// # Safety
+/// `T` must have no padding bytes, and on little-endian targets, its memory representation must
+/// be equal to its encoding. On other targets, the values are hashed one by one.
+#[inline]
+unsafe fn update_bulk<T: Fingerprint, U: Update>(data: &[T], hasher: &mut U) {
+    #[cfg(target_endian = "little")]
+    {
+        let bytes =
+            core::slice::from_raw_parts(data.as_ptr().cast::<u8>(), core::mem::size_of_val(data));
+        hasher.update(bytes);
+    }
+
+    #[cfg(not(target_endian = "little"))]
+    for element in data {
+        element.fingerprint(hasher);
+    }
+}
+
 macro_rules! impl_primitive {
     ($type: ty) => {
         impl Fingerprint for $type {
@@ -68,6 +88,13 @@ macro_rules! impl_primitive {
             fn fingerprint<U: Update>(&self, hasher: &mut U) {
                 hasher.update(&self.to_le_bytes());
             }
+
+            #[inline]
+            fn fingerprint_slice<U: Update>(data: &[Self], hasher: &mut U) {
+                // SAFETY: Integers and floats have no padding bytes and are hashed as their
+                // little-endian bytes
+                unsafe { update_bulk(data, hasher) }
+            }
         }
     };
 }
@@ -92,6 +119,13 @@ macro_rules! impl_through_cast {
             fn fingerprint<U: Update>(&self, hasher: &mut U) {
                 (*self as $cast).fingerprint(hasher);
             }
+
+            #[inline]
+            fn fingerprint_slice<U: Update>(data: &[Self], hasher: &mut U) {
+                // SAFETY: `char` and `bool` have the size and memory representation of the
+                // integer they are cast to
+                unsafe { update_bulk(data, hasher) }
+            }
         }
     };
 }
@@ -212,9 +246,7 @@ impl Fingerprint for isize {
 impl<T: Fingerprint, const N: usize> Fingerprint for [T; N] {
     #[inline(always)]
     fn fingerprint<U: Update>(&self, hasher: &mut U) {
-        for i in self {
-            i.fingerprint(hasher);
-        }
+        T::fingerprint_slice(self, hasher);
     }
 }
 
@@ -317,6 +349,7 @@ impl<T: ?Sized> Fingerprint for core::marker::PhantomData<T> {
     fn fingerprint<U: Update>(&self, _hasher: &mut U) {}
 }
 
+#[cfg(feature = "alloc")]
 macro_rules! impl_ordered_seq {
     ($type: ty $(,$bound: tt)?) => {
         impl<T: Fingerprint $(+ $bound)?> Fingerprint for $type {
@@ -332,15 +365,37 @@ macro_rules! impl_ordered_seq {
     };
 }
 
-impl_ordered_seq!([T]);
+macro_rules! impl_contiguous_seq {
+    ($type: ty) => {
+        impl<T: Fingerprint> Fingerprint for $type {
+            #[inline]
+            fn fingerprint<U: Update>(&self, hasher: &mut U) {
+                self.len().fingerprint(hasher);
+                T::fingerprint_slice(self, hasher);
+            }
+        }
+    };
+}
+
+impl_contiguous_seq!([T]);
 #[cfg(feature = "alloc")]
-impl_ordered_seq!(alloc::vec::Vec<T>);
+impl_contiguous_seq!(alloc::vec::Vec<T>);
 #[cfg(feature = "alloc")]
 impl_ordered_seq!(alloc::collections::BTreeSet<T>, Ord);
 #[cfg(feature = "alloc")]
 impl_ordered_seq!(alloc::collections::LinkedList<T>);
+
 #[cfg(feature = "alloc")]
-impl_ordered_seq!(alloc::collections::VecDeque<T>);
+impl<T: Fingerprint> Fingerprint for alloc::collections::VecDeque<T> {
+    #[inline]
+    fn fingerprint<U: Update>(&self, hasher: &mut U) {
+        self.len().fingerprint(hasher);
+
+        let (front, back) = self.as_slices();
+        T::fingerprint_slice(front, hasher);
+        T::fingerprint_slice(back, hasher);
+    }
+}
 
 #[cfg(feature = "alloc")]
 macro_rules! impl_unordered_seq {
@@ -20,14 +20,60 @@
 //! let payload = "Hello world!";
 //! let hash = fingerprint::<Blake2b512>((env!("CARGO_PKG_VERSION"), payload));
 //! ```
+//!
+//! When using the derive macro, you can instead version individual types with the
+//! `#[fingerprint(version = N)]` attribute, so that bumping the version of one type only changes
+//! the hashes of that type:
+//!
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # {
+//! use blake2::Blake2b512;
+//! use fingerprint_struct::{fingerprint, Fingerprint};
+//!
+//! #[derive(Fingerprint)]
+//! #[fingerprint(version = 2)]
+//! struct Payload {
+//!     message: &'static str,
+//! }
+//!
+//! let hash = fingerprint::<Blake2b512>(Payload { message: "Hello world!" });
+//! # }
+//! ```
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[doc(hidden)]
+#[path = "private.rs"]
+pub mod __private;
+mod buffered;
+mod canonical;
+mod decode;
+mod dynamic;
+mod encode;
 mod impls;
+mod schema;
+mod tee;
+mod typed;
+#[cfg(feature = "alloc")]
+pub mod value;
+
+pub use digest;
 
-use digest::{FixedOutput, Output, Update};
+use digest::{ExtendableOutput, FixedOutput, InvalidOutputSize, Output, Update, VariableOutput};
+
+pub use buffered::BufferedUpdate;
+pub use canonical::{CanonicalFloat, FingerprintCanonical};
+pub use decode::{decode_from_slice, DecodeError, FingerprintDecode};
+pub use dynamic::DynFingerprint;
+#[cfg(feature = "alloc")]
+pub use encode::encode_to_vec;
+pub use encode::{encode_into, encoded_len};
+pub use schema::{FingerprintSchema, SchemaStack};
+pub use tee::{HasherTuple, Tee};
+pub use typed::{ParseDigestError, TypedDigest};
 
 /// A data structure whose cryptographic hash can be computed by a hasher.
 ///
@@ -37,6 +83,21 @@ use digest::{FixedOutput, Output, Update};
 pub trait Fingerprint {
     /// Use this value to update a hasher.
     fn fingerprint<U: Update>(&self, hasher: &mut U);
+
+    /// Use a slice of values to update a hasher.
+    ///
+    /// This must be equivalent to calling [`fingerprint`](Fingerprint::fingerprint) on each
+    /// element in order. Slices, arrays and other sequences are hashed through this method, so
+    /// that primitives can override it to update the hasher with all elements at once.
+    #[inline]
+    fn fingerprint_slice<U: Update>(data: &[Self], hasher: &mut U)
+    where
+        Self: Sized,
+    {
+        for element in data {
+            element.fingerprint(hasher);
+        }
+    }
 }
 
 /// Calculate the cryptographic hash of a data structure using the default hasher of a given type.
@@ -57,6 +118,17 @@ pub trait Fingerprint {
 /// let hash = fingerprint::<Blake2b512>("Hello world!");
 /// println!("{hash:?}");
 /// ```
+///
+/// Wrapping the hasher in a [`BufferedUpdate`] collects small writes into a buffer first, which
+/// can be faster for some hashers, without affecting the hash.
+///
+/// ```
+/// use sha2::Sha256;
+/// use fingerprint_struct::{fingerprint, BufferedUpdate};
+///
+/// let hash = fingerprint::<BufferedUpdate<Sha256>>((1u8, 2u8, 3u8));
+/// assert_eq!(hash, fingerprint::<Sha256>((1u8, 2u8, 3u8)));
+/// ```
 pub fn fingerprint<H: Update + FixedOutput + Default>(value: impl Fingerprint) -> Output<H> {
     fingerprint_with(value, H::default())
 }
@@ -79,7 +151,240 @@ pub fn fingerprint_with<H: Update + FixedOutput, T: Fingerprint>(
     hasher.finalize_fixed()
 }
 
-/// Implements the Fingerprint trait for a custom struct or enum.
+/// Calculate the cryptographic hashes of a data structure using the default hashers of several
+/// types at once, traversing the value only once.
+///
+/// The hashes are the same as the ones returned by [`fingerprint`] for each hasher.
+///
+/// # Examples
+/// ```
+/// use blake2::Blake2b512;
+/// use sha2::Sha256;
+/// use fingerprint_struct::{fingerprint, fingerprint_many};
+///
+/// let (sha256, blake2b) = fingerprint_many::<(Sha256, Blake2b512)>("Hello world!");
+/// assert_eq!(sha256, fingerprint::<Sha256>("Hello world!"));
+/// assert_eq!(blake2b, fingerprint::<Blake2b512>("Hello world!"));
+/// ```
+pub fn fingerprint_many<H: HasherTuple + Default>(value: impl Fingerprint) -> H::Output {
+    fingerprint_many_with(value, H::default())
+}
+
+/// Calculate the cryptographic hashes of a data structure using a tuple of provided hashers,
+/// traversing the value only once.
+///
+/// The hashes are the same as the ones returned by [`fingerprint_with`] for each hasher.
+pub fn fingerprint_many_with<H: HasherTuple, T: Fingerprint>(value: T, hashers: H) -> H::Output {
+    let mut hasher = tee::TupleUpdate(hashers);
+    value.fingerprint(&mut hasher);
+    hasher.0.finalize_all()
+}
+
+/// Calculate the cryptographic hash of a data structure using an extendable-output function (XOF),
+/// returning a reader from which an arbitrary number of bytes can be read.
+///
+/// # Examples
+/// ```
+/// use sha3::{digest::XofReader, Shake128};
+/// use fingerprint_struct::fingerprint_xof;
+///
+/// let mut reader = fingerprint_xof::<Shake128>("Hello world!");
+/// let mut hash = [0u8; 20];
+/// reader.read(&mut hash);
+/// ```
+pub fn fingerprint_xof<H: ExtendableOutput + Default>(value: impl Fingerprint) -> H::Reader {
+    let mut hasher = H::default();
+    value.fingerprint(&mut hasher);
+    hasher.finalize_xof()
+}
+
+/// Calculate the cryptographic hash of a data structure using an extendable-output function (XOF),
+/// filling the whole output buffer.
+///
+/// # Examples
+/// ```
+/// use sha3::Shake256;
+/// use fingerprint_struct::fingerprint_xof_into;
+///
+/// let mut hash = [0u8; 64];
+/// fingerprint_xof_into::<Shake256>("Hello world!", &mut hash);
+/// ```
+pub fn fingerprint_xof_into<H: ExtendableOutput + Default>(
+    value: impl Fingerprint,
+    out: &mut [u8],
+) {
+    let mut hasher = H::default();
+    value.fingerprint(&mut hasher);
+    hasher.finalize_xof_into(out);
+}
+
+/// Calculate the cryptographic hash of a data structure using a hasher with an output size chosen
+/// at runtime.
+///
+/// Returns an error if the hasher doesn't support an output of `len` bytes.
+///
+/// # Examples
+/// ```
+/// use blake2::Blake2bVar;
+/// use fingerprint_struct::fingerprint_var;
+///
+/// let hash = fingerprint_var::<Blake2bVar>("Hello world!", 20).unwrap();
+/// assert_eq!(hash.len(), 20);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn fingerprint_var<H: VariableOutput>(
+    value: impl Fingerprint,
+    len: usize,
+) -> Result<alloc::vec::Vec<u8>, InvalidOutputSize> {
+    let mut result = alloc::vec![0; len];
+    fingerprint_var_into::<H>(value, &mut result)?;
+    Ok(result)
+}
+
+/// Calculate the cryptographic hash of a data structure using a hasher with an output size chosen
+/// at runtime, filling the whole output buffer.
+///
+/// Returns an error if the hasher doesn't support an output of the length of the buffer.
+///
+/// # Examples
+/// ```
+/// use blake2::Blake2bVar;
+/// use fingerprint_struct::fingerprint_var_into;
+///
+/// let mut hash = [0u8; 20];
+/// fingerprint_var_into::<Blake2bVar>("Hello world!", &mut hash).unwrap();
+/// ```
+pub fn fingerprint_var_into<H: VariableOutput>(
+    value: impl Fingerprint,
+    out: &mut [u8],
+) -> Result<(), InvalidOutputSize> {
+    let mut hasher = H::new(out.len())?;
+    value.fingerprint(&mut hasher);
+    hasher
+        .finalize_variable(out)
+        .expect("the buffer has the size the hasher was created with");
+    Ok(())
+}
+
+/// Calculate the message authentication code (MAC) of a data structure using a given key.
+///
+/// Works with any MAC implementing the traits from the [`digest`] crate, like HMAC or keyed
+/// BLAKE2. Returns an error if the MAC doesn't support keys of the given length.
+///
+/// The returned [`CtOutput`](digest::CtOutput) is compared in constant time. To check a tag
+/// received from elsewhere, use [`verify_mac`]. Requires the `mac` feature.
+///
+/// # Examples
+/// ```
+/// use hmac::Hmac;
+/// use sha2::Sha256;
+/// use fingerprint_struct::fingerprint_mac;
+///
+/// let tag = fingerprint_mac::<Hmac<Sha256>>(b"secret key", "Hello world!").unwrap();
+/// let bytes = tag.into_bytes();
+/// ```
+#[cfg(feature = "mac")]
+pub fn fingerprint_mac<M: digest::Mac + digest::KeyInit + Update>(
+    key: &[u8],
+    value: impl Fingerprint,
+) -> Result<digest::CtOutput<M>, digest::InvalidLength> {
+    let mut mac = <M as digest::KeyInit>::new_from_slice(key)?;
+    value.fingerprint(&mut mac);
+    Ok(mac.finalize())
+}
+
+/// Check that the message authentication code (MAC) of a data structure using a given key matches
+/// `tag`.
+///
+/// The comparison is done in constant time. A key of a length unsupported by the MAC is also
+/// reported as a [`MacError`](digest::MacError).
+///
+/// # Examples
+/// ```
+/// use hmac::Hmac;
+/// use sha2::Sha256;
+/// use fingerprint_struct::{fingerprint_mac, verify_mac};
+///
+/// let tag = fingerprint_mac::<Hmac<Sha256>>(b"secret key", "Hello world!").unwrap();
+/// let tag = tag.into_bytes();
+///
+/// assert!(verify_mac::<Hmac<Sha256>>(b"secret key", "Hello world!", &tag).is_ok());
+/// assert!(verify_mac::<Hmac<Sha256>>(b"secret key", "Goodbye world!", &tag).is_err());
+/// ```
+#[cfg(feature = "mac")]
+pub fn verify_mac<M: digest::Mac + digest::KeyInit + Update>(
+    key: &[u8],
+    value: impl Fingerprint,
+    tag: &[u8],
+) -> Result<(), digest::MacError> {
+    let mut mac = <M as digest::KeyInit>::new_from_slice(key).map_err(|_| digest::MacError)?;
+    value.fingerprint(&mut mac);
+    mac.verify_slice(tag)
+}
+
+/// Calculate the cryptographic hash of a value using the default hasher of a given type, and return
+/// it as a [`TypedDigest`] tied to the type of the value.
+///
+/// The hash is the same as the one returned by [`fingerprint`].
+///
+/// # Examples
+/// ```
+/// use sha2::Sha256;
+/// use fingerprint_struct::{fingerprint, fingerprint_typed, TypedDigest};
+///
+/// let user: TypedDigest<Sha256, (u32, &str)> = fingerprint_typed(&(42, "alice"));
+/// assert_eq!(user.into_output(), fingerprint::<Sha256>((42u32, "alice")));
+/// ```
+pub fn fingerprint_typed<H: Update + FixedOutput + Default, T: Fingerprint + ?Sized>(
+    value: &T,
+) -> TypedDigest<H, T> {
+    fingerprint_typed_with(value, H::default())
+}
+
+/// Calculate the cryptographic hash of a value using provided hasher, and return it as a
+/// [`TypedDigest`] tied to the type of the value.
+///
+/// The hash is the same as the one returned by [`fingerprint_with`].
+pub fn fingerprint_typed_with<H: Update + FixedOutput, T: Fingerprint + ?Sized>(
+    value: &T,
+    mut hasher: H,
+) -> TypedDigest<H, T> {
+    value.fingerprint(&mut hasher);
+    TypedDigest::new(hasher.finalize_fixed())
+}
+
+/// Calculate the cryptographic hash of the structure of a type using the default hasher of a given
+/// type.
+///
+/// The hash changes whenever the names of types or fields, the order of fields, the tags of enum
+/// variants or the structure of contained types change. Comparing it to a stored value in a test
+/// can catch changes to persisted types that weren't accompanied by a version bump.
+///
+/// # Examples
+/// ```
+/// use blake2::Blake2b512;
+/// use fingerprint_struct::{schema_fingerprint, FingerprintSchema};
+///
+/// # #[cfg(feature = "derive")]
+/// # {
+/// #[derive(FingerprintSchema)]
+/// struct Point {
+///     x: i32,
+///     y: i32
+/// }
+///
+/// let hash = schema_fingerprint::<Blake2b512, Point>();
+/// println!("{hash:?}");
+/// # }
+/// ```
+pub fn schema_fingerprint<H: Update + FixedOutput + Default, T: FingerprintSchema + ?Sized>(
+) -> Output<H> {
+    let mut hasher = H::default();
+    SchemaStack::default().fingerprint::<T, H>(&mut hasher);
+    hasher.finalize_fixed()
+}
+
+/// Implements the Fingerprint trait for a custom struct, enum or union.
 ///
 /// Explicit enum discriminants will be used when provided.
 ///
@@ -106,5 +411,410 @@ pub fn fingerprint_with<H: Update + FixedOutput, T: Fingerprint>(
 ///     LoggedIn { token: String }
 /// }
 /// ```
+///
+/// # Attributes
+///
+/// The generated implementation can be customized using `#[fingerprint(...)]` attributes.
+///
+/// ## Container attributes
+///
+/// - `#[fingerprint(version = N)]`: The version number `N` is hashed as a [`u32`] before any
+///   fields. For enums, it's hashed before the discriminant. Changing the version changes the
+///   hashes of all values of the type.
+///
+/// ```
+/// use fingerprint_struct::Fingerprint;
+///
+/// #[derive(Fingerprint)]
+/// #[fingerprint(version = 3)]
+/// struct Point {
+///     x: i32,
+///     y: i32
+/// }
+/// ```
+///
+/// - `#[fingerprint(domain)]` or `#[fingerprint(domain = "name")]`: The domain name is hashed as
+///   a [`str`] before anything else, including the version. When no name is provided, the name of
+///   the type is used. This prevents values of types with the same layout from having the same
+///   hash.
+///
+/// ```
+/// use fingerprint_struct::Fingerprint;
+///
+/// #[derive(Fingerprint)]
+/// #[fingerprint(domain)]
+/// struct UserId(u64);
+///
+/// #[derive(Fingerprint)]
+/// #[fingerprint(domain = "com.example.order")]
+/// struct OrderId(u64);
+/// ```
+///
+/// - `#[fingerprint(tag = "name")]`: Can only be used on enums. Instead of the discriminant, the
+///   name of each variant is hashed as a [`str`]. This means that adding, removing or reordering
+///   variants doesn't change the hashes of other variants. The default behaviour can be requested
+///   explicitly using `#[fingerprint(tag = "discriminant")]`.
+///
+/// ```
+/// use fingerprint_struct::Fingerprint;
+///
+/// #[derive(Fingerprint)]
+/// #[fingerprint(tag = "name")]
+/// enum Shape {
+///     Square(f32),
+///     Circle(f32)
+/// }
+/// ```
+///
+/// - `#[fingerprint(named_fields)]`: The name of each named field is hashed as a [`str`] before
+///   its value, so renaming a field or swapping two fields of the same type changes the hash.
+///   Enum variants with unnamed fields aren't affected.
+/// - `#[fingerprint(sorted_fields)]`: Named fields are hashed in the order of their names instead
+///   of the order in which they are declared, so reordering fields in the source code doesn't
+///   change the hash. Can be combined with `named_fields`.
+///
+/// ```
+/// use fingerprint_struct::Fingerprint;
+///
+/// #[derive(Fingerprint)]
+/// #[fingerprint(named_fields, sorted_fields)]
+/// struct Config {
+///     width: u32,
+///     height: u32
+/// }
+/// ```
+///
+/// - `#[fingerprint(bound = "T: Fingerprint")]`: The given predicates are used in the where
+///   clause of the generated implementation instead of the inferred ones. By default, each field
+///   type is required to implement [`Fingerprint`], which doesn't work for some recursive types.
+///   An empty string can be used to remove the inferred predicates.
+///
+/// ```
+/// use fingerprint_struct::Fingerprint;
+///
+/// # #[cfg(feature = "alloc")]
+/// #[derive(Fingerprint)]
+/// #[fingerprint(bound = "T: Fingerprint")]
+/// struct Node<T> {
+///     value: T,
+///     children: Vec<Node<T>>
+/// }
+/// ```
+///
+/// - `#[fingerprint(crate = "path")]`: The path used to refer to this crate in the generated
+///   code, `::fingerprint_struct` by default. This allows using the derive macro through a crate
+///   that re-exports `fingerprint_struct`.
+///
+/// ```
+/// mod facade {
+///     pub mod fingerprint {
+///         pub use fingerprint_struct::*;
+///     }
+/// }
+///
+/// #[derive(facade::fingerprint::Fingerprint)]
+/// #[fingerprint(crate = "facade::fingerprint")]
+/// struct Point {
+///     x: i32,
+///     y: i32
+/// }
+/// ```
+///
+/// - `#[fingerprint(remote = "path::Type")]`: Derives the fingerprint of a type from another
+///   crate, which can't implement [`Fingerprint`] because of the orphan rule. The definition of
+///   the remote type is copied to a local type with this attribute. Instead of implementing
+///   [`Fingerprint`], an associated function `fingerprint(value: &path::Type, hasher: &mut U)` is
+///   generated, which can be used with the `with` field attribute. The fields of the local type
+///   must match the fields of the remote type, which must be public. Since the local type is never
+///   constructed, it usually needs `#[allow(dead_code)]`.
+///
+/// ```
+/// use fingerprint_struct::Fingerprint;
+///
+/// mod dep {
+///     pub struct Duration {
+///         pub secs: u64,
+///         pub nanos: u32
+///     }
+/// }
+///
+/// #[allow(dead_code)]
+/// #[derive(Fingerprint)]
+/// #[fingerprint(remote = "dep::Duration")]
+/// struct DurationDef {
+///     secs: u64,
+///     nanos: u32
+/// }
+///
+/// #[derive(Fingerprint)]
+/// struct Timeout {
+///     #[fingerprint(with = "DurationDef::fingerprint")]
+///     duration: dep::Duration
+/// }
+/// ```
+///
+/// - `#[fingerprint(unsafe_discriminant = "expr")]`: Can only be used on unions. The expression
+///   is evaluated to determine the active field, by matching it against the patterns given by the
+///   `when` attributes of the fields. The index of the active field is hashed as an [`isize`],
+///   followed by the field, so a union is hashed like an enum with a variant for each field.
+///
+///   The active field is read without further checks, which is why the attribute is unsafe: for
+///   every value of the union, the expression must select a field that is initialized and valid
+///   for its type, otherwise hashing the union is undefined behavior.
+///
+/// ```
+/// use fingerprint_struct::Fingerprint;
+///
+/// #[repr(C)]
+/// #[derive(Clone, Copy, Fingerprint)]
+/// struct Ipv4 {
+///     version: u8,
+///     address: [u8; 4]
+/// }
+///
+/// #[repr(C)]
+/// #[derive(Clone, Copy, Fingerprint)]
+/// struct Ipv6 {
+///     version: u8,
+///     address: [u16; 8]
+/// }
+///
+/// #[derive(Fingerprint)]
+/// // SAFETY: The version is the first byte of all fields, and the other fields are only written
+/// // together with their version.
+/// #[fingerprint(unsafe_discriminant = "unsafe { self.version }")]
+/// union Address {
+///     #[fingerprint(skip)]
+///     version: u8,
+///     #[fingerprint(when = "4")]
+///     v4: Ipv4,
+///     #[fingerprint(when = "6")]
+///     v6: Ipv6,
+///     #[fingerprint(when = "_")]
+///     unknown: u8
+/// }
+/// ```
+///
+/// - `#[fingerprint(bytes)]`: Can only be used on unions. The bytes of the union are hashed like a
+///   byte array, with each integer, float or char of the first field in little-endian byte order,
+///   so `Register { int: 1 }` below is hashed the same way on all targets. Values written through
+///   a field with a different layout, like `bytes`, are still read like the first field, so their
+///   hash depends on the byte order of the target. The types of the fields can only be integers,
+///   floats, [`bool`], [`char`] or arrays of those, and all fields must have the same size as the
+///   union, which is checked at compile time.
+///
+/// ```
+/// use fingerprint_struct::Fingerprint;
+///
+/// #[derive(Fingerprint)]
+/// #[fingerprint(bytes)]
+/// union Register {
+///     int: u64,
+///     float: f64,
+///     bytes: [u8; 8]
+/// }
+/// ```
+///
+/// ## Variant attributes
+///
+/// - `#[fingerprint(tag = N)]`: The integer `N` is hashed instead of the discriminant of the
+///   variant. The discriminant itself isn't changed.
+/// - `#[fingerprint(tag = "name")]`: The string `name` is hashed instead of the name of the
+///   variant. Can only be used if the enum has `#[fingerprint(tag = "name")]`.
+///
+/// It's a compile error if multiple variants end up with the same tag.
+///
+/// ```
+/// use fingerprint_struct::Fingerprint;
+///
+/// #[repr(u8)]
+/// #[derive(Fingerprint)]
+/// enum Status {
+///     Active,
+///     #[fingerprint(tag = 10)]
+///     Suspended,
+///     Deleted
+/// }
+/// ```
+///
+/// ## Field attributes
+///
+/// - `#[fingerprint(skip)]`: The field is left out of the fingerprint. Its type doesn't need to
+///   implement [`Fingerprint`].
+///
+/// ```
+/// use fingerprint_struct::Fingerprint;
+///
+/// # #[cfg(feature = "alloc")]
+/// #[derive(Fingerprint)]
+/// struct CachedPage {
+///     url: String,
+///     #[fingerprint(skip)]
+///     hits: core::cell::Cell<u32>
+/// }
+/// ```
+///
+/// - `#[fingerprint(with = "path")]`: The field is hashed by calling the function at `path`
+///   instead of [`Fingerprint::fingerprint`]. The function must be callable as
+///   `fn<U: Update>(&T, &mut U)`, where `T` is the type of the field. The field type doesn't need
+///   to implement [`Fingerprint`].
+///
+/// ```
+/// use digest::Update;
+/// use fingerprint_struct::Fingerprint;
+///
+/// struct Celsius(f64);
+///
+/// fn fingerprint_celsius<U: Update>(value: &Celsius, hasher: &mut U) {
+///     value.0.fingerprint(hasher);
+/// }
+///
+/// #[derive(Fingerprint)]
+/// struct Measurement {
+///     #[fingerprint(with = "fingerprint_celsius")]
+///     temperature: Celsius
+/// }
+/// ```
+///
+/// - `#[fingerprint(as = "Type")]`: The field is converted to `Type` using
+///   [`Into`] before being hashed, so it's hashed exactly like a value of `Type`. The conversion
+///   is performed on a reference, so `Type` must implement `From<&T>`, where `T` is the type of the
+///   field.
+///
+/// ```
+/// use fingerprint_struct::Fingerprint;
+///
+/// struct Rgb(u32);
+///
+/// impl From<&Rgb> for [u8; 3] {
+///     fn from(rgb: &Rgb) -> Self {
+///         let [_, r, g, b] = rgb.0.to_be_bytes();
+///         [r, g, b]
+///     }
+/// }
+///
+/// #[derive(Fingerprint)]
+/// struct Pixel {
+///     #[fingerprint(as = "[u8; 3]")]
+///     color: Rgb
+/// }
+/// ```
+///
+/// - `#[fingerprint(rename = "name")]`: The field is treated as if it was called `name` by the
+///   `named_fields` and `sorted_fields` container attributes.
+///
+/// ```
+/// use fingerprint_struct::Fingerprint;
+///
+/// #[derive(Fingerprint)]
+/// #[fingerprint(named_fields)]
+/// struct Config {
+///     #[fingerprint(rename = "width")]
+///     screen_width: u32
+/// }
+/// ```
+///
+/// - `#[fingerprint(skip_if_default)]`: The field is left out of the fingerprint if it's equal
+///   to its [`Default`] value. To keep the encoding unambiguous, a struct or variant with such a
+///   field hashes the number of fields that aren't left out as a [`usize`] first, and every field
+///   is preceded by its name hashed as a [`str`], like with `named_fields`. Unnamed fields are
+///   named after their index. Once a type has such a field, new fields can be added to it without
+///   changing the hashes of existing values, provided that the new fields are set to their
+///   defaults. Adding the first one changes the hashes.
+/// - `#[fingerprint(skip_if = "path")]`: Like `skip_if_default`, but the field is left out if the
+///   function at `path` returns `true`. The function must be callable as `fn(&T) -> bool`.
+///
+/// ```
+/// use fingerprint_struct::Fingerprint;
+///
+/// #[derive(Fingerprint)]
+/// struct Config {
+///     width: u32,
+///     height: u32,
+///     #[fingerprint(skip_if_default)]
+///     refresh_rate: Option<u32>,
+///     // Added in a later version
+///     #[fingerprint(skip_if_default)]
+///     vsync: bool,
+/// }
+/// ```
+///
+/// - `#[fingerprint(bound = "T: Fingerprint")]`: Like the container attribute, but only replaces
+///   the predicates inferred from this field.
+/// - `#[fingerprint(when = "pattern")]`: Can only be used on fields of unions with the
+///   `unsafe_discriminant` attribute. The field is active if the discriminant matches the pattern.
+///   It's required on all fields that aren't skipped.
+/// - `#[fingerprint(canonical_float)]` or `#[fingerprint(canonical_float = "normalize_zero")]`:
+///   The field is hashed using [`FingerprintCanonical`], like it was wrapped in
+///   [`CanonicalFloat`], so all NaNs contained in it are hashed the same way. With
+///   `normalize_zero`, `-0.0` is also hashed like `0.0`.
+///
+/// ```
+/// use fingerprint_struct::Fingerprint;
+///
+/// #[derive(Fingerprint)]
+/// struct Samples {
+///     #[fingerprint(canonical_float = "normalize_zero")]
+///     values: Vec<(f64, f32)>
+/// }
+/// ```
 #[cfg(feature = "derive")]
 pub use fingerprint_struct_derive::Fingerprint;
+
+/// Implements the FingerprintSchema trait for a custom struct, enum or union.
+///
+/// The `#[fingerprint(...)]` attributes of the [`Fingerprint`] derive macro are taken into
+/// account, so skipped fields aren't described and renamed fields are described using their new
+/// name. Fields with the `with` attribute are described by the path of the function. The
+/// description includes the version and domain of the type.
+///
+/// Each type parameter is required to implement [`FingerprintSchema`]. The `bound` attributes
+/// only apply to the [`Fingerprint`] derive macro.
+///
+/// # Examples
+/// ```
+/// use fingerprint_struct::{Fingerprint, FingerprintSchema};
+///
+/// # #[cfg(feature = "alloc")]
+/// #[derive(Fingerprint, FingerprintSchema)]
+/// #[fingerprint(version = 2)]
+/// enum Message {
+///     Ping,
+///     Text { author: String, body: String }
+/// }
+/// ```
+#[cfg(feature = "derive")]
+pub use fingerprint_struct_derive::FingerprintSchema;
+
+/// Implements the FingerprintDecode trait for a custom struct or enum.
+///
+/// The `#[fingerprint(...)]` attributes of the [`Fingerprint`] derive macro are taken into
+/// account, so the derived implementation decodes the bytes produced by the derived
+/// [`Fingerprint`] implementation. Skipped fields are set to their [`Default`] value. The `with`,
+/// `as`, `skip_if` and `remote` attributes aren't supported, as they don't allow the value to be
+/// reconstructed.
+///
+/// The predicates of `bound` attributes are only used by [`Fingerprint`]. Instead, the derived
+/// implementation requires the type parameters to implement [`FingerprintDecode`], all of them
+/// for a container attribute and the ones used by the type of the field for a field attribute.
+///
+/// # Examples
+/// ```
+/// use fingerprint_struct::{decode_from_slice, encode_to_vec, Fingerprint, FingerprintDecode};
+///
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// #[derive(Fingerprint, FingerprintDecode, PartialEq, Debug)]
+/// #[fingerprint(version = 2)]
+/// enum Message {
+///     Ping,
+///     Text { author: String, body: String }
+/// }
+///
+/// let message = Message::Text { author: "Alice".into(), body: "Hi".into() };
+/// let bytes = encode_to_vec(&message);
+/// assert_eq!(decode_from_slice(&bytes), Ok(message));
+/// # }
+/// ```
+#[cfg(feature = "derive")]
+pub use fingerprint_struct_derive::FingerprintDecode;
@@ -0,0 +1,63 @@
+//! Items used by the code generated by the derive macro. Not part of the public API.
+
+use core::marker::PhantomData;
+
+use digest::Update;
+
+pub use crate::decode::{decode_expected, decode_str};
+
+/// A type whose values don't contain any padding bytes, so all of their bytes are initialized.
+///
+/// # Safety
+///
+/// Implementations must only be provided for types without padding bytes, which consist of
+/// integers, floats or chars of `ELEMENT_SIZE` bytes each.
+pub unsafe trait NoPadding: Copy {
+    /// The size of the integers, floats or chars the type consists of.
+    const ELEMENT_SIZE: usize;
+}
+
+macro_rules! impl_no_padding {
+    ($($type: ty),*) => {
+        $(unsafe impl NoPadding for $type {
+            const ELEMENT_SIZE: usize = core::mem::size_of::<$type>();
+        })*
+    };
+}
+
+impl_no_padding!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool, char
+);
+
+unsafe impl<T: NoPadding, const N: usize> NoPadding for [T; N] {
+    const ELEMENT_SIZE: usize = T::ELEMENT_SIZE;
+}
+
+/// Checks at compile time that two types have the same size.
+pub struct AssertSameSize<A, B>(PhantomData<(A, B)>);
+
+impl<A, B> AssertSameSize<A, B> {
+    pub const OK: () = assert!(
+        core::mem::size_of::<A>() == core::mem::size_of::<B>(),
+        "all fields of a union with `#[fingerprint(bytes)]` must have the size of the union"
+    );
+}
+
+/// Hashes the bytes of a value of type `T`, with each of its integers, floats and chars in
+/// little-endian byte order.
+pub fn update_le<T: NoPadding, U: Update>(hasher: &mut U, bytes: &[u8]) {
+    if cfg!(target_endian = "little") {
+        hasher.update(bytes);
+        return;
+    }
+
+    // The largest elements are 128-bit integers
+    let mut buffer = [0u8; 16];
+
+    for element in bytes.chunks_exact(T::ELEMENT_SIZE) {
+        let buffer = &mut buffer[..element.len()];
+        buffer.copy_from_slice(element);
+        buffer.reverse();
+        hasher.update(buffer);
+    }
+}
@@ -0,0 +1,258 @@
+use digest::Update;
+
+use crate::Fingerprint;
+
+/// A type whose structure can be described to a hasher.
+///
+/// The description includes the names of types and fields, the order of fields, the tags of enum
+/// variants and the descriptions of all contained types, so it changes whenever the way values
+/// of the type are hashed by [`Fingerprint`] might change. This can be used to detect changes to
+/// persisted types using [`schema_fingerprint`](crate::schema_fingerprint).
+///
+/// Implementations are provided for all types from [`std`] that implement [`Fingerprint`], and can
+/// be derived using `#[derive(FingerprintSchema)]`.
+pub trait FingerprintSchema {
+    /// Use a description of this type to update a hasher.
+    ///
+    /// Contained types should be described using [`SchemaStack::fingerprint`], which makes it
+    /// possible to describe recursive types.
+    fn fingerprint_schema<U: Update>(hasher: &mut U, stack: SchemaStack<'_>);
+}
+
+/// The types whose descriptions are currently being computed.
+///
+/// If a type is encountered while it's being described, a reference to it is hashed instead of
+/// its description, so recursive types don't cause infinite recursion.
+///
+/// Types are recognized by their [`type_name`](core::any::type_name), because comparing their
+/// [`TypeId`](core::any::TypeId) would require them to be `'static`. Type names aren't
+/// guaranteed to be unique, so a type containing a different type with the same name, for
+/// example the same type from another version of its crate, is described like a recursive type.
+/// The descriptions of such types can collide with the descriptions of actually recursive types.
+#[derive(Clone, Copy, Default)]
+pub struct SchemaStack<'a> {
+    top: Option<&'a Frame<'a>>,
+}
+
+struct Frame<'a> {
+    type_name: &'static str,
+    parent: SchemaStack<'a>,
+}
+
+impl<'a> SchemaStack<'a> {
+    /// Use a description of type `T` to update a hasher.
+    pub fn fingerprint<T: FingerprintSchema + ?Sized, U: Update>(self, hasher: &mut U) {
+        let type_name = core::any::type_name::<T>();
+
+        let mut depth = 0usize;
+        let mut current = self.top;
+
+        while let Some(frame) = current {
+            if frame.type_name == type_name {
+                "recursive".fingerprint(hasher);
+                depth.fingerprint(hasher);
+                return;
+            }
+
+            depth += 1;
+            current = frame.parent.top;
+        }
+
+        let frame = Frame {
+            type_name,
+            parent: self,
+        };
+
+        T::fingerprint_schema(hasher, SchemaStack { top: Some(&frame) });
+    }
+}
+
+macro_rules! impl_named {
+    ($type: ty, $name: literal) => {
+        impl FingerprintSchema for $type {
+            #[inline]
+            fn fingerprint_schema<U: Update>(hasher: &mut U, _stack: SchemaStack<'_>) {
+                $name.fingerprint(hasher);
+            }
+        }
+    };
+}
+
+macro_rules! impl_generic {
+    ($type: ty, $name: literal, $($param: ident),*) => {
+        impl<$($param: FingerprintSchema),*> FingerprintSchema for $type {
+            #[inline]
+            fn fingerprint_schema<U: Update>(hasher: &mut U, stack: SchemaStack<'_>) {
+                $name.fingerprint(hasher);
+                $(stack.fingerprint::<$param, U>(hasher);)*
+            }
+        }
+    };
+}
+
+macro_rules! impl_transparent {
+    ($type: ty $(, $bound: path)?) => {
+        impl<T: FingerprintSchema + ?Sized $(+ $bound)?> FingerprintSchema for $type {
+            #[inline]
+            fn fingerprint_schema<U: Update>(hasher: &mut U, stack: SchemaStack<'_>) {
+                T::fingerprint_schema(hasher, stack);
+            }
+        }
+    };
+}
+
+// Pointers are hashed like the values they point to, so they have the same description
+impl_transparent!(&T);
+impl_transparent!(&mut T);
+#[cfg(feature = "alloc")]
+impl_transparent!(alloc::boxed::Box<T>);
+#[cfg(feature = "alloc")]
+impl_transparent!(alloc::rc::Rc<T>);
+#[cfg(feature = "alloc")]
+impl_transparent!(alloc::sync::Arc<T>);
+#[cfg(feature = "alloc")]
+impl_transparent!(alloc::borrow::Cow<'_, T>, alloc::borrow::ToOwned);
+impl_transparent!(core::cell::Cell<T>);
+
+impl_named!(i8, "i8");
+impl_named!(i16, "i16");
+impl_named!(i32, "i32");
+impl_named!(i64, "i64");
+impl_named!(i128, "i128");
+impl_named!(isize, "isize");
+impl_named!(u8, "u8");
+impl_named!(u16, "u16");
+impl_named!(u32, "u32");
+impl_named!(u64, "u64");
+impl_named!(u128, "u128");
+impl_named!(usize, "usize");
+impl_named!(f32, "f32");
+impl_named!(f64, "f64");
+impl_named!(char, "char");
+impl_named!(bool, "bool");
+
+impl_named!(core::num::NonZeroI8, "NonZeroI8");
+impl_named!(core::num::NonZeroI16, "NonZeroI16");
+impl_named!(core::num::NonZeroI32, "NonZeroI32");
+impl_named!(core::num::NonZeroI64, "NonZeroI64");
+impl_named!(core::num::NonZeroI128, "NonZeroI128");
+impl_named!(core::num::NonZeroIsize, "NonZeroIsize");
+impl_named!(core::num::NonZeroU8, "NonZeroU8");
+impl_named!(core::num::NonZeroU16, "NonZeroU16");
+impl_named!(core::num::NonZeroU32, "NonZeroU32");
+impl_named!(core::num::NonZeroU64, "NonZeroU64");
+impl_named!(core::num::NonZeroU128, "NonZeroU128");
+impl_named!(core::num::NonZeroUsize, "NonZeroUsize");
+
+impl_named!(core::sync::atomic::AtomicBool, "AtomicBool");
+impl_named!(core::sync::atomic::AtomicI8, "AtomicI8");
+impl_named!(core::sync::atomic::AtomicI16, "AtomicI16");
+impl_named!(core::sync::atomic::AtomicI32, "AtomicI32");
+impl_named!(core::sync::atomic::AtomicI64, "AtomicI64");
+impl_named!(core::sync::atomic::AtomicIsize, "AtomicIsize");
+impl_named!(core::sync::atomic::AtomicU8, "AtomicU8");
+impl_named!(core::sync::atomic::AtomicU16, "AtomicU16");
+impl_named!(core::sync::atomic::AtomicU32, "AtomicU32");
+impl_named!(core::sync::atomic::AtomicU64, "AtomicU64");
+impl_named!(core::sync::atomic::AtomicUsize, "AtomicUsize");
+
+impl<T: FingerprintSchema, const N: usize> FingerprintSchema for [T; N] {
+    #[inline]
+    fn fingerprint_schema<U: Update>(hasher: &mut U, stack: SchemaStack<'_>) {
+        "array".fingerprint(hasher);
+        N.fingerprint(hasher);
+        stack.fingerprint::<T, U>(hasher);
+    }
+}
+
+macro_rules! impl_tuple {
+    ($len: literal, $($name: ident)*) => {
+        impl<$($name: FingerprintSchema),*> FingerprintSchema for ($($name,)*) {
+            #[inline]
+            #[allow(unused_variables)] // In case of the empty tuple
+            fn fingerprint_schema<U: Update>(hasher: &mut U, stack: SchemaStack<'_>) {
+                "tuple".fingerprint(hasher);
+                $len.fingerprint(hasher);
+                $(stack.fingerprint::<$name, U>(hasher);)*
+            }
+        }
+    };
+}
+
+impl_tuple!(0usize,);
+impl_tuple!(1usize, T0);
+impl_tuple!(2usize, T0 T1);
+impl_tuple!(3usize, T0 T1 T2);
+impl_tuple!(4usize, T0 T1 T2 T3);
+impl_tuple!(5usize, T0 T1 T2 T3 T4);
+impl_tuple!(6usize, T0 T1 T2 T3 T4 T5);
+impl_tuple!(7usize, T0 T1 T2 T3 T4 T5 T6);
+impl_tuple!(8usize, T0 T1 T2 T3 T4 T5 T6 T7);
+impl_tuple!(9usize, T0 T1 T2 T3 T4 T5 T6 T7 T8);
+impl_tuple!(10usize, T0 T1 T2 T3 T4 T5 T6 T7 T8 T9);
+impl_tuple!(11usize, T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10);
+impl_tuple!(12usize, T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11);
+impl_tuple!(13usize, T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12);
+impl_tuple!(14usize, T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 T13);
+impl_tuple!(15usize, T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 T13 T14);
+impl_tuple!(16usize, T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 T13 T14 T15);
+
+impl_named!(str, "str");
+#[cfg(feature = "alloc")]
+impl_named!(alloc::string::String, "String");
+#[cfg(feature = "std")]
+impl_named!(std::ffi::CStr, "CStr");
+#[cfg(feature = "std")]
+impl_named!(std::ffi::CString, "CString");
+
+impl_generic!(Option<T>, "Option", T);
+impl_generic!(Result<T, E>, "Result", T, E);
+
+impl<T: ?Sized> FingerprintSchema for core::marker::PhantomData<T> {
+    #[inline]
+    fn fingerprint_schema<U: Update>(hasher: &mut U, _stack: SchemaStack<'_>) {
+        "PhantomData".fingerprint(hasher);
+    }
+}
+
+impl_generic!([T], "slice", T);
+#[cfg(feature = "alloc")]
+impl_generic!(alloc::vec::Vec<T>, "Vec", T);
+#[cfg(feature = "alloc")]
+impl_generic!(alloc::collections::BTreeSet<T>, "BTreeSet", T);
+#[cfg(feature = "alloc")]
+impl_generic!(alloc::collections::LinkedList<T>, "LinkedList", T);
+#[cfg(feature = "alloc")]
+impl_generic!(alloc::collections::VecDeque<T>, "VecDeque", T);
+#[cfg(feature = "alloc")]
+impl_generic!(alloc::collections::BinaryHeap<T>, "BinaryHeap", T);
+#[cfg(feature = "std")]
+impl_generic!(std::collections::HashSet<T>, "HashSet", T);
+#[cfg(feature = "alloc")]
+impl_generic!(alloc::collections::BTreeMap<K, V>, "BTreeMap", K, V);
+#[cfg(feature = "std")]
+impl_generic!(std::collections::HashMap<K, V>, "HashMap", K, V);
+
+impl_generic!(core::ops::Range<T>, "Range", T);
+impl_generic!(core::ops::RangeInclusive<T>, "RangeInclusive", T);
+impl_generic!(core::ops::Bound<T>, "Bound", T);
+
+impl_named!(core::time::Duration, "Duration");
+#[cfg(all(feature = "std", feature = "os"))]
+impl_named!(std::time::SystemTime, "SystemTime");
+
+#[cfg(feature = "std")]
+impl_named!(std::net::Ipv4Addr, "Ipv4Addr");
+#[cfg(feature = "std")]
+impl_named!(std::net::Ipv6Addr, "Ipv6Addr");
+#[cfg(feature = "std")]
+impl_named!(std::net::IpAddr, "IpAddr");
+#[cfg(feature = "std")]
+impl_named!(std::net::SocketAddrV4, "SocketAddrV4");
+#[cfg(feature = "std")]
+impl_named!(std::net::SocketAddrV6, "SocketAddrV6");
+#[cfg(feature = "std")]
+impl_named!(std::net::SocketAddr, "SocketAddr");
+
+impl_generic!(core::num::Wrapping<T>, "Wrapping", T);
+impl_generic!(core::cmp::Reverse<T>, "Reverse", T);
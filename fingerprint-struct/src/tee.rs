@@ -0,0 +1,111 @@
+use digest::{FixedOutput, Output, Reset, Update};
+
+/// An adapter which passes all bytes to two hashers, so that a value is only traversed once to
+/// compute both hashes.
+///
+/// More hashers can be combined by nesting, or by passing a tuple of hashers to
+/// [`fingerprint_many`](crate::fingerprint_many).
+///
+/// # Examples
+/// ```
+/// use blake2::Blake2b512;
+/// use fingerprint_struct::{fingerprint, Fingerprint, Tee};
+/// use sha2::{digest::FixedOutput, Sha256};
+///
+/// let mut hasher = Tee::<Sha256, Blake2b512>::default();
+/// "Hello world!".fingerprint(&mut hasher);
+///
+/// let (sha256, blake2b) = hasher.into_inner();
+/// assert_eq!(sha256.finalize_fixed(), fingerprint::<Sha256>("Hello world!"));
+/// assert_eq!(blake2b.finalize_fixed(), fingerprint::<Blake2b512>("Hello world!"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Tee<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Tee<A, B> {
+    /// Creates a new adapter which passes the bytes to both hashers.
+    pub fn new(first: A, second: B) -> Self {
+        Tee { first, second }
+    }
+
+    /// Gets references to both hashers.
+    pub fn get_ref(&self) -> (&A, &B) {
+        (&self.first, &self.second)
+    }
+
+    /// Returns both hashers.
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+impl<A: Update, B: Update> Update for Tee<A, B> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.first.update(data);
+        self.second.update(data);
+    }
+}
+
+impl<A: Reset, B: Reset> Reset for Tee<A, B> {
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+    }
+}
+
+/// A tuple of hashers which can be updated together and finalized into a tuple of outputs.
+///
+/// Implemented for tuples of up to 8 hashers implementing [`Update`] and [`FixedOutput`].
+/// Used by [`fingerprint_many`](crate::fingerprint_many).
+pub trait HasherTuple {
+    /// A tuple of the outputs of the hashers.
+    type Output;
+
+    /// Update all hashers with the same bytes.
+    fn update_all(&mut self, data: &[u8]);
+
+    /// Finalize all hashers.
+    fn finalize_all(self) -> Self::Output;
+}
+
+macro_rules! impl_hasher_tuple {
+    ($($num: tt: $name: ident)*) => {
+        impl<$($name: Update + FixedOutput),*> HasherTuple for ($($name,)*) {
+            type Output = ($(Output<$name>,)*);
+
+            #[inline]
+            fn update_all(&mut self, data: &[u8]) {
+                $(
+                    self.$num.update(data);
+                )*
+            }
+
+            fn finalize_all(self) -> Self::Output {
+                ($(self.$num.finalize_fixed(),)*)
+            }
+        }
+    };
+}
+
+impl_hasher_tuple!(0: H0);
+impl_hasher_tuple!(0: H0 1: H1);
+impl_hasher_tuple!(0: H0 1: H1 2: H2);
+impl_hasher_tuple!(0: H0 1: H1 2: H2 3: H3);
+impl_hasher_tuple!(0: H0 1: H1 2: H2 3: H3 4: H4);
+impl_hasher_tuple!(0: H0 1: H1 2: H2 3: H3 4: H4 5: H5);
+impl_hasher_tuple!(0: H0 1: H1 2: H2 3: H3 4: H4 5: H5 6: H6);
+impl_hasher_tuple!(0: H0 1: H1 2: H2 3: H3 4: H4 5: H5 6: H6 7: H7);
+
+/// Passes bytes to all hashers in a tuple.
+pub(crate) struct TupleUpdate<H>(pub H);
+
+impl<H: HasherTuple> Update for TupleUpdate<H> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.0.update_all(data);
+    }
+}
@@ -0,0 +1,202 @@
+use core::{cmp::Ordering, fmt, hash::Hash, marker::PhantomData, str::FromStr};
+
+use digest::{Output, OutputSizeUser, Update};
+use subtle::ConstantTimeEq;
+
+use crate::Fingerprint;
+
+/// The hash of a value of type `T` calculated by hasher `H`.
+///
+/// Unlike a bare [`Output`], the type remembers which type and algorithm produced the hash, so
+/// hashes of different types can't be mixed up. It can be formatted and parsed as hexadecimal.
+///
+/// Equality is checked in constant time, so comparing a hash against an untrusted one doesn't
+/// reveal the length of their common prefix. Ordering isn't constant-time.
+///
+/// # Examples
+/// ```
+/// use sha2::Sha256;
+/// use fingerprint_struct::{fingerprint_typed, TypedDigest};
+///
+/// let hash: TypedDigest<Sha256, str> = fingerprint_typed("Hello world!");
+/// let text = hash.to_string();
+///
+/// assert_eq!(text.len(), 64);
+/// assert_eq!(text.parse::<TypedDigest<Sha256, str>>(), Ok(hash));
+/// ```
+pub struct TypedDigest<H: OutputSizeUser, T: ?Sized> {
+    output: Output<H>,
+    value_type: PhantomData<fn(&T)>,
+}
+
+impl<H: OutputSizeUser, T: ?Sized> TypedDigest<H, T> {
+    /// Creates a typed hash from the output of a hasher.
+    ///
+    /// The caller is responsible for the hash being calculated from a value of type `T`.
+    pub fn new(output: Output<H>) -> Self {
+        TypedDigest {
+            output,
+            value_type: PhantomData,
+        }
+    }
+
+    /// Gets the bytes of the hash.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.output
+    }
+
+    /// Gets the output of the hasher.
+    pub fn into_output(self) -> Output<H> {
+        self.output
+    }
+}
+
+impl<H: OutputSizeUser, T: ?Sized> Clone for TypedDigest<H, T> {
+    fn clone(&self) -> Self {
+        Self::new(self.output.clone())
+    }
+}
+
+impl<H: OutputSizeUser, T: ?Sized> Copy for TypedDigest<H, T> where Output<H>: Copy {}
+
+impl<H: OutputSizeUser, T: ?Sized> PartialEq for TypedDigest<H, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes().ct_eq(other.as_bytes()).into()
+    }
+}
+
+impl<H: OutputSizeUser, T: ?Sized> Eq for TypedDigest<H, T> {}
+
+impl<H: OutputSizeUser, T: ?Sized> PartialOrd for TypedDigest<H, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<H: OutputSizeUser, T: ?Sized> Ord for TypedDigest<H, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_bytes().cmp(other.as_bytes())
+    }
+}
+
+impl<H: OutputSizeUser, T: ?Sized> Hash for TypedDigest<H, T> {
+    fn hash<S: core::hash::Hasher>(&self, state: &mut S) {
+        self.as_bytes().hash(state);
+    }
+}
+
+impl<H: OutputSizeUser, T: ?Sized> AsRef<[u8]> for TypedDigest<H, T> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<H: OutputSizeUser, T: ?Sized> From<TypedDigest<H, T>> for Output<H> {
+    fn from(digest: TypedDigest<H, T>) -> Self {
+        digest.output
+    }
+}
+
+impl<H: OutputSizeUser, T: ?Sized> fmt::Debug for TypedDigest<H, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TypedDigest")
+            .field(&format_args!("{self:x}"))
+            .finish()
+    }
+}
+
+impl<H: OutputSizeUser, T: ?Sized> fmt::Display for TypedDigest<H, T> {
+    /// Formats the hash as lowercase hexadecimal.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+impl<H: OutputSizeUser, T: ?Sized> fmt::LowerHex for TypedDigest<H, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+
+        for byte in self.as_bytes() {
+            write!(f, "{byte:02x}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<H: OutputSizeUser, T: ?Sized> fmt::UpperHex for TypedDigest<H, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+
+        for byte in self.as_bytes() {
+            write!(f, "{byte:02X}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<H: OutputSizeUser, T: ?Sized> FromStr for TypedDigest<H, T> {
+    type Err = ParseDigestError;
+
+    /// Parses a hash from hexadecimal digits of either case, without a prefix.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.as_bytes();
+        let mut output = Output::<H>::default();
+
+        if digits.len() != output.len() * 2 {
+            return Err(ParseDigestError::InvalidLength);
+        }
+
+        for (byte, pair) in output.iter_mut().zip(digits.chunks_exact(2)) {
+            *byte = (parse_digit(pair[0])? << 4) | parse_digit(pair[1])?;
+        }
+
+        Ok(Self::new(output))
+    }
+}
+
+fn parse_digit(digit: u8) -> Result<u8, ParseDigestError> {
+    match digit {
+        b'0'..=b'9' => Ok(digit - b'0'),
+        b'a'..=b'f' => Ok(digit - b'a' + 10),
+        b'A'..=b'F' => Ok(digit - b'A' + 10),
+        _ => Err(ParseDigestError::InvalidCharacter),
+    }
+}
+
+impl<H: OutputSizeUser, T: ?Sized> Fingerprint for TypedDigest<H, T> {
+    /// Hashes the bytes of the hash, like an array of bytes.
+    #[inline]
+    fn fingerprint<U: Update>(&self, hasher: &mut U) {
+        hasher.update(self.as_bytes());
+    }
+}
+
+/// An error returned when parsing a [`TypedDigest`] fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseDigestError {
+    /// The string doesn't contain exactly two digits per byte of the hash.
+    InvalidLength,
+    /// The string contains a character that isn't a hexadecimal digit.
+    InvalidCharacter,
+}
+
+impl fmt::Display for ParseDigestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            ParseDigestError::InvalidLength => "hash has an invalid length",
+            ParseDigestError::InvalidCharacter => "hash contains a non-hexadecimal character",
+        };
+
+        f.write_str(message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseDigestError {}
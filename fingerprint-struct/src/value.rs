@@ -0,0 +1,298 @@
+//! A dynamically typed value, for hashing data whose shape is only known at runtime.
+
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+use core::cmp::Ordering;
+
+use digest::Update;
+
+use crate::Fingerprint;
+
+/// A dynamically typed value which is hashed exactly like the equivalent static type.
+///
+/// Each variant corresponds to one or more static types:
+///
+/// - The integer, float, [`bool`], [`char`] and [`Unit`](Value::Unit) variants are hashed like the
+///   primitive they contain.
+/// - [`String`](Value::String) is hashed like [`str`] and [`String`].
+/// - [`Bytes`](Value::Bytes) is hashed like `[u8]` and `Vec<u8>`.
+/// - [`Option`](Value::Option) is hashed like [`Option`].
+/// - [`Seq`](Value::Seq) is hashed like slices, [`Vec`] and other sequences, which are preceded by
+///   their length.
+/// - [`Tuple`](Value::Tuple) is hashed like tuples, arrays and derived structs, whose elements are
+///   hashed in order without a length.
+/// - [`Set`](Value::Set) and [`Map`](Value::Map) are hashed like [`BTreeSet`] and [`BTreeMap`].
+/// - [`Variant`](Value::Variant) is hashed like an enum variant, its tag followed by its fields.
+///   Derived enums use an [`Isize`](Value::Isize) tag unless they have a `#[repr]` attribute, or a
+///   [`String`](Value::String) tag if they have a `#[fingerprint(tag = "name")]` attribute.
+///   Standard enums like [`Result`] use an [`U8`](Value::U8) tag.
+///
+/// Derived structs with a `domain`, a `version` or `named_fields` correspond to a
+/// [`Tuple`](Value::Tuple) which also contains the domain, version or field names in the order
+/// they are hashed in. Derived structs and variants with fields that might be left out also
+/// contain the number of fields that aren't left out as an [`Usize`](Value::Usize), followed by
+/// the name and value of each of these fields.
+///
+/// Values are ordered first by variant, then by their contents. For values of the same variant,
+/// the order agrees with the order of the equivalent static types, so sets and maps are sorted
+/// the same way, with some exceptions:
+///
+/// - Derived types whose fields aren't hashed in the order they are declared in.
+/// - Enum variants, which are ordered by their tags and then by their fields. Derived enums are
+///   ordered by the order their variants are declared in instead, which differs for enums with a
+///   `#[fingerprint(tag = "name")]` attribute or with discriminants that aren't ascending.
+///
+/// Floats are ordered using [`f64::total_cmp`].
+///
+/// # Examples
+/// ```
+/// use blake2::Blake2b512;
+/// use fingerprint_struct::{fingerprint, value::Value};
+///
+/// let value = Value::Tuple(vec![
+///     Value::U32(42),
+///     Value::String("config".into()),
+///     Value::Option(Some(Box::new(Value::Bool(true)))),
+/// ]);
+///
+/// assert_eq!(
+///     fingerprint::<Blake2b512>(&value),
+///     fingerprint::<Blake2b512>((42u32, "config", Some(true)))
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub enum Value {
+    /// The unit type `()`, which isn't hashed at all.
+    Unit,
+    /// A [`bool`].
+    Bool(bool),
+    /// An [`u8`].
+    U8(u8),
+    /// An [`u16`].
+    U16(u16),
+    /// An [`u32`].
+    U32(u32),
+    /// An [`u64`].
+    U64(u64),
+    /// An [`u128`].
+    U128(u128),
+    /// An [`usize`], hashed using a variable number of bytes.
+    Usize(usize),
+    /// An [`i8`].
+    I8(i8),
+    /// An [`i16`].
+    I16(i16),
+    /// An [`i32`].
+    I32(i32),
+    /// An [`i64`].
+    I64(i64),
+    /// An [`i128`].
+    I128(i128),
+    /// An [`isize`], hashed using a variable number of bytes.
+    Isize(isize),
+    /// An [`f32`].
+    F32(f32),
+    /// An [`f64`].
+    F64(f64),
+    /// A [`char`].
+    Char(char),
+    /// A string, preceded by its length.
+    String(String),
+    /// A sequence of bytes, preceded by its length.
+    Bytes(Vec<u8>),
+    /// An optional value.
+    Option(Option<Box<Value>>),
+    /// A sequence of values, preceded by its length.
+    Seq(Vec<Value>),
+    /// A fixed number of values, without a length.
+    Tuple(Vec<Value>),
+    /// A set of values, preceded by its length and hashed in ascending order.
+    Set(BTreeSet<Value>),
+    /// A map of keys to values, preceded by its length and hashed in ascending order of keys.
+    Map(BTreeMap<Value, Value>),
+    /// An enum variant, which consists of a tag followed by the fields of the variant.
+    Variant {
+        /// The tag identifying the variant.
+        tag: Box<Value>,
+        /// The fields of the variant.
+        fields: Vec<Value>,
+    },
+}
+
+impl Value {
+    /// Gets the position of the variant of the value, used for ordering values of different
+    /// variants.
+    fn rank(&self) -> u8 {
+        match self {
+            Value::Unit => 0,
+            Value::Bool(_) => 1,
+            Value::U8(_) => 2,
+            Value::U16(_) => 3,
+            Value::U32(_) => 4,
+            Value::U64(_) => 5,
+            Value::U128(_) => 6,
+            Value::Usize(_) => 7,
+            Value::I8(_) => 8,
+            Value::I16(_) => 9,
+            Value::I32(_) => 10,
+            Value::I64(_) => 11,
+            Value::I128(_) => 12,
+            Value::Isize(_) => 13,
+            Value::F32(_) => 14,
+            Value::F64(_) => 15,
+            Value::Char(_) => 16,
+            Value::String(_) => 17,
+            Value::Bytes(_) => 18,
+            Value::Option(_) => 19,
+            Value::Seq(_) => 20,
+            Value::Tuple(_) => 21,
+            Value::Set(_) => 22,
+            Value::Map(_) => 23,
+            Value::Variant { .. } => 24,
+        }
+    }
+}
+
+impl Fingerprint for Value {
+    fn fingerprint<U: Update>(&self, hasher: &mut U) {
+        match self {
+            Value::Unit => {}
+            Value::Bool(value) => value.fingerprint(hasher),
+            Value::U8(value) => value.fingerprint(hasher),
+            Value::U16(value) => value.fingerprint(hasher),
+            Value::U32(value) => value.fingerprint(hasher),
+            Value::U64(value) => value.fingerprint(hasher),
+            Value::U128(value) => value.fingerprint(hasher),
+            Value::Usize(value) => value.fingerprint(hasher),
+            Value::I8(value) => value.fingerprint(hasher),
+            Value::I16(value) => value.fingerprint(hasher),
+            Value::I32(value) => value.fingerprint(hasher),
+            Value::I64(value) => value.fingerprint(hasher),
+            Value::I128(value) => value.fingerprint(hasher),
+            Value::Isize(value) => value.fingerprint(hasher),
+            Value::F32(value) => value.fingerprint(hasher),
+            Value::F64(value) => value.fingerprint(hasher),
+            Value::Char(value) => value.fingerprint(hasher),
+            Value::String(value) => value.fingerprint(hasher),
+            Value::Bytes(value) => value.fingerprint(hasher),
+            Value::Option(value) => value.fingerprint(hasher),
+            Value::Seq(values) => values.fingerprint(hasher),
+            Value::Tuple(values) => {
+                for value in values {
+                    value.fingerprint(hasher);
+                }
+            }
+            Value::Set(values) => values.fingerprint(hasher),
+            Value::Map(entries) => entries.fingerprint(hasher),
+            Value::Variant { tag, fields } => {
+                tag.fingerprint(hasher);
+
+                for field in fields {
+                    field.fingerprint(hasher);
+                }
+            }
+        }
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Value {}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+            (Value::U8(a), Value::U8(b)) => a.cmp(b),
+            (Value::U16(a), Value::U16(b)) => a.cmp(b),
+            (Value::U32(a), Value::U32(b)) => a.cmp(b),
+            (Value::U64(a), Value::U64(b)) => a.cmp(b),
+            (Value::U128(a), Value::U128(b)) => a.cmp(b),
+            (Value::Usize(a), Value::Usize(b)) => a.cmp(b),
+            (Value::I8(a), Value::I8(b)) => a.cmp(b),
+            (Value::I16(a), Value::I16(b)) => a.cmp(b),
+            (Value::I32(a), Value::I32(b)) => a.cmp(b),
+            (Value::I64(a), Value::I64(b)) => a.cmp(b),
+            (Value::I128(a), Value::I128(b)) => a.cmp(b),
+            (Value::Isize(a), Value::Isize(b)) => a.cmp(b),
+            (Value::F32(a), Value::F32(b)) => a.total_cmp(b),
+            (Value::F64(a), Value::F64(b)) => a.total_cmp(b),
+            (Value::Char(a), Value::Char(b)) => a.cmp(b),
+            (Value::String(a), Value::String(b)) => a.cmp(b),
+            (Value::Bytes(a), Value::Bytes(b)) => a.cmp(b),
+            (Value::Option(a), Value::Option(b)) => a.cmp(b),
+            (Value::Seq(a), Value::Seq(b)) => a.cmp(b),
+            (Value::Tuple(a), Value::Tuple(b)) => a.cmp(b),
+            (Value::Set(a), Value::Set(b)) => a.cmp(b),
+            (Value::Map(a), Value::Map(b)) => a.cmp(b),
+            (
+                Value::Variant { tag, fields },
+                Value::Variant {
+                    tag: other_tag,
+                    fields: other_fields,
+                },
+            ) => (tag, fields).cmp(&(other_tag, other_fields)),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($type: ty, $variant: ident) => {
+        impl From<$type> for Value {
+            fn from(value: $type) -> Self {
+                Value::$variant(value)
+            }
+        }
+    };
+}
+
+impl_from!(bool, Bool);
+impl_from!(u8, U8);
+impl_from!(u16, U16);
+impl_from!(u32, U32);
+impl_from!(u64, U64);
+impl_from!(u128, U128);
+impl_from!(usize, Usize);
+impl_from!(i8, I8);
+impl_from!(i16, I16);
+impl_from!(i32, I32);
+impl_from!(i64, I64);
+impl_from!(i128, I128);
+impl_from!(isize, Isize);
+impl_from!(f32, F32);
+impl_from!(f64, F64);
+impl_from!(char, Char);
+impl_from!(String, String);
+
+impl From<()> for Value {
+    fn from(_: ()) -> Self {
+        Value::Unit
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::String(value.into())
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Self {
+        Value::Option(value.map(|value| Box::new(value.into())))
+    }
+}
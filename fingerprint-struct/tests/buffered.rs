@@ -0,0 +1,65 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use digest::Update;
+use fingerprint_struct::{BufferedUpdate, Fingerprint};
+use mock_digest::MockDigest;
+
+fn assert_same_buffered<T: Fingerprint>(value: T) {
+    let mut hasher_a = MockDigest::default();
+    value.fingerprint(&mut hasher_a);
+
+    let mut hasher_b = BufferedUpdate::<MockDigest, 8>::default();
+    value.fingerprint(&mut hasher_b);
+    let hasher_b = hasher_b.into_inner();
+
+    assert_eq!(hasher_a.as_ref(), hasher_b.as_ref());
+}
+
+#[test]
+fn buffered_small_writes() {
+    assert_same_buffered(());
+    assert_same_buffered(1u8);
+    assert_same_buffered((1u8, 2u16, 3u32, 4u64, 5u128));
+    assert_same_buffered([(1u8, 'a'); 20]);
+}
+
+#[test]
+fn buffered_large_writes() {
+    assert_same_buffered([7u8; 8]);
+    assert_same_buffered((1u8, [2u32; 40], 3u8));
+    assert_same_buffered(("a long string that doesn't fit into the buffer", 1u8));
+}
+
+#[test]
+fn buffered_flush() {
+    let mut hasher = BufferedUpdate::<MockDigest, 8>::default();
+
+    hasher.update(&[1, 2, 3]);
+    assert_eq!(hasher.get_ref().as_ref(), &[]);
+
+    hasher.update(&[4, 5, 6, 7, 8]);
+    assert_eq!(hasher.get_ref().as_ref(), &[]);
+
+    hasher.update(&[9]);
+    assert_eq!(hasher.get_ref().as_ref(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+
+    hasher.flush();
+    assert_eq!(hasher.get_ref().as_ref(), &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+}
+
+#[test]
+fn buffered_fingerprint() {
+    use blake2::Blake2b512;
+    use fingerprint_struct::{fingerprint, fingerprint_with};
+
+    let value = (1u8, -2i16, "three", [4.0f64; 5], Some('5'));
+
+    assert_eq!(
+        fingerprint::<BufferedUpdate<Blake2b512>>(value),
+        fingerprint::<Blake2b512>(value)
+    );
+    assert_eq!(
+        fingerprint_with(value, BufferedUpdate::<_, 16>::new(Blake2b512::default())),
+        fingerprint::<Blake2b512>(value)
+    );
+}
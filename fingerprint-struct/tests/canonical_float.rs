@@ -0,0 +1,130 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fingerprint_struct::{CanonicalFloat, Fingerprint};
+use mock_digest::MockDigest;
+
+fn assert_same_fingerprint<A: Fingerprint, B: Fingerprint>(a: A, b: B) {
+    let mut hasher_a = MockDigest::default();
+    a.fingerprint(&mut hasher_a);
+
+    let mut hasher_b = MockDigest::default();
+    b.fingerprint(&mut hasher_b);
+
+    assert_eq!(hasher_a.as_ref(), hasher_b.as_ref());
+}
+
+fn assert_different_fingerprint<A: Fingerprint, B: Fingerprint>(a: A, b: B) {
+    let mut hasher_a = MockDigest::default();
+    a.fingerprint(&mut hasher_a);
+
+    let mut hasher_b = MockDigest::default();
+    b.fingerprint(&mut hasher_b);
+
+    assert_ne!(hasher_a.as_ref(), hasher_b.as_ref());
+}
+
+#[test]
+fn canonical_float_f32_nan() {
+    let payload = f32::from_bits(0x7fc0_1234);
+    let negative = f32::from_bits(0xffc0_0000);
+
+    assert_same_fingerprint(CanonicalFloat::<_>(payload), 0x7fc0_0000u32);
+    assert_same_fingerprint(CanonicalFloat::<_>(negative), 0x7fc0_0000u32);
+    assert_same_fingerprint(CanonicalFloat::<_>(f32::NAN), 0x7fc0_0000u32);
+}
+
+#[test]
+fn canonical_float_f64_nan() {
+    let payload = f64::from_bits(0x7ff8_0000_0000_1234);
+    let signaling = f64::from_bits(0x7ff0_0000_0000_0001);
+
+    assert_same_fingerprint(CanonicalFloat::<_>(payload), 0x7ff8_0000_0000_0000u64);
+    assert_same_fingerprint(CanonicalFloat::<_>(signaling), 0x7ff8_0000_0000_0000u64);
+    assert_same_fingerprint(CanonicalFloat::<_>(-f64::NAN), 0x7ff8_0000_0000_0000u64);
+}
+
+#[test]
+fn canonical_float_zero() {
+    assert_different_fingerprint(CanonicalFloat::<_>(-0.0f64), 0.0f64);
+    assert_same_fingerprint(CanonicalFloat::<_>(-0.0f64), -0.0f64);
+
+    assert_same_fingerprint(CanonicalFloat::<_, true>(-0.0f64), 0.0f64);
+    assert_same_fingerprint(CanonicalFloat::<_, true>(-0.0f32), 0.0f32);
+}
+
+#[test]
+fn canonical_float_ordinary_values() {
+    assert_same_fingerprint(CanonicalFloat::<_>(1.5f32), 1.5f32);
+    assert_same_fingerprint(CanonicalFloat::<_, true>(-2.25f64), -2.25f64);
+    assert_same_fingerprint(CanonicalFloat::<_>(f64::INFINITY), f64::INFINITY);
+    assert_same_fingerprint(CanonicalFloat::<_>((42u8, "abc", 'x')), (42u8, "abc", 'x'));
+}
+
+#[test]
+fn canonical_float_nested() {
+    let nan = f64::from_bits(0x7ff8_0000_0000_1234);
+    let canonical = f64::NAN;
+
+    assert_same_fingerprint(CanonicalFloat::<_>([nan, 1.0]), [canonical, 1.0]);
+    assert_same_fingerprint(
+        CanonicalFloat::<_, true>((nan, -0.0f32, Some(nan))),
+        (canonical, 0.0f32, Some(canonical)),
+    );
+    assert_same_fingerprint(CanonicalFloat::<_>(&[nan, 2.0][..]), &[canonical, 2.0][..]);
+    assert_same_fingerprint(
+        CanonicalFloat::<Result<f64, f32>>(Err(f32::from_bits(0xffc0_0001))),
+        Err::<f64, f32>(f32::NAN),
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn canonical_float_collections() {
+    extern crate alloc;
+
+    use alloc::{boxed::Box, collections::VecDeque, vec, vec::Vec};
+
+    let nan = f64::from_bits(0xfff8_0000_0000_0001);
+    let canonical = f64::NAN;
+
+    assert_same_fingerprint(
+        CanonicalFloat::<_, true>(vec![nan, -0.0, 3.0]),
+        vec![canonical, 0.0, 3.0],
+    );
+    assert_same_fingerprint(
+        CanonicalFloat::<_>(VecDeque::from(vec![nan])),
+        VecDeque::from(vec![canonical]),
+    );
+    assert_same_fingerprint(
+        CanonicalFloat::<_>(Box::new(vec![(nan, 1u8)])),
+        Box::new(vec![(canonical, 1u8)]),
+    );
+    assert_same_fingerprint(
+        CanonicalFloat::<Vec<Vec<f64>>>(vec![vec![nan], vec![]]),
+        vec![vec![canonical], vec![]],
+    );
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_canonical_float() {
+    #[derive(Fingerprint)]
+    struct Samples {
+        #[fingerprint(canonical_float)]
+        values: [f64; 2],
+        #[fingerprint(canonical_float = "normalize_zero")]
+        offset: (f32, u8),
+        raw: f64,
+    }
+
+    let nan = f64::from_bits(0x7ff8_0000_0000_1234);
+
+    assert_same_fingerprint(
+        Samples {
+            values: [nan, -0.0],
+            offset: (-0.0, 1),
+            raw: -0.0,
+        },
+        ([f64::NAN, -0.0], (0.0f32, 1u8), -0.0f64),
+    );
+}
@@ -0,0 +1,6 @@
+#[test]
+#[cfg(feature = "derive")]
+fn derive_compile_fail() {
+    let tests = trybuild::TestCases::new();
+    tests.compile_fail("tests/ui/*.rs");
+}
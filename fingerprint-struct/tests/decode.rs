@@ -0,0 +1,412 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::{
+    cell::Cell,
+    cmp::Reverse,
+    fmt::Debug,
+    marker::PhantomData,
+    num::{NonZeroI16, NonZeroU8, NonZeroUsize, Wrapping},
+    ops::Bound,
+    sync::atomic::{AtomicBool, AtomicI32, AtomicUsize},
+    time::Duration,
+};
+
+use fingerprint_struct::{decode_from_slice, DecodeError, Fingerprint, FingerprintDecode};
+use mock_digest::MockDigest;
+
+fn encode<T: Fingerprint + ?Sized>(value: &T) -> MockDigest {
+    let mut hasher = MockDigest::default();
+    value.fingerprint(&mut hasher);
+    hasher
+}
+
+/// Checks that decoding the encoding of a value results in a value with the same encoding.
+fn assert_roundtrip<T: Fingerprint + FingerprintDecode>(value: T) {
+    let bytes = encode(&value);
+    let decoded: T = decode_from_slice(bytes.as_ref()).unwrap();
+
+    assert_eq!(encode(&decoded).as_ref(), bytes.as_ref());
+}
+
+/// Checks that decoding the encoding of a value results in an equal value.
+fn assert_roundtrip_eq<T: Fingerprint + FingerprintDecode + PartialEq + Debug>(value: T) {
+    let bytes = encode(&value);
+
+    assert_eq!(decode_from_slice::<T>(bytes.as_ref()), Ok(value));
+}
+
+fn assert_error<T: FingerprintDecode + Debug>(bytes: &[u8], error: DecodeError) {
+    assert_eq!(decode_from_slice::<T>(bytes).unwrap_err(), error);
+}
+
+#[test]
+fn decode_primitives() {
+    assert_roundtrip_eq(42u8);
+    assert_roundtrip_eq(-42i8);
+    assert_roundtrip_eq(0x1234u16);
+    assert_roundtrip_eq(-0x1234i16);
+    assert_roundtrip_eq(0x1234_5678u32);
+    assert_roundtrip_eq(i32::MIN);
+    assert_roundtrip_eq(u64::MAX);
+    assert_roundtrip_eq(i64::MIN);
+    assert_roundtrip_eq(u128::MAX);
+    assert_roundtrip_eq(i128::MIN);
+    assert_roundtrip_eq(1.5f32);
+    assert_roundtrip_eq(-0.0f64);
+    assert_roundtrip(f64::NAN);
+    assert_roundtrip_eq(true);
+    assert_roundtrip_eq(false);
+    assert_roundtrip_eq('ř');
+}
+
+#[test]
+fn decode_variable_size_integers() {
+    for value in [0, 1, 127, 128, 300, u32::MAX as usize, usize::MAX] {
+        assert_roundtrip_eq(value);
+    }
+
+    for value in [0, 1, -1, 63, -64, 64, isize::MAX, isize::MIN] {
+        assert_roundtrip_eq(value);
+    }
+}
+
+#[test]
+fn decode_special_numeric() {
+    assert_roundtrip_eq(NonZeroU8::new(1).unwrap());
+    assert_roundtrip_eq(NonZeroI16::new(-300).unwrap());
+    assert_roundtrip_eq(NonZeroUsize::new(300).unwrap());
+    assert_roundtrip(AtomicBool::new(true));
+    assert_roundtrip(AtomicI32::new(-5));
+    assert_roundtrip(AtomicUsize::new(1000));
+    assert_roundtrip_eq(Wrapping(5u8));
+    assert_roundtrip_eq(Reverse(-5i64));
+}
+
+#[test]
+fn decode_fixed_sequences() {
+    assert_roundtrip_eq(());
+    assert_roundtrip_eq([1u8, 2, 3]);
+    assert_roundtrip_eq([[1u16; 2]; 3]);
+    assert_roundtrip_eq([0u8; 0]);
+    assert_roundtrip_eq((1u8,));
+    assert_roundtrip_eq((1u8, -2i64, 'c', true));
+    assert_roundtrip((
+        1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8, 13u8, 14u8, 15u8, 16u8,
+    ));
+}
+
+#[test]
+fn decode_std_types() {
+    assert_roundtrip_eq(Some(5u32));
+    assert_roundtrip_eq(None::<u32>);
+    assert_roundtrip_eq(Ok::<u8, i8>(1));
+    assert_roundtrip_eq(Err::<u8, i8>(-1));
+    assert_roundtrip_eq(PhantomData::<u8>);
+    assert_roundtrip_eq(Cell::new(5u8));
+    assert_roundtrip_eq(1u8..5);
+    assert_roundtrip_eq(1u8..=5);
+    assert_roundtrip_eq(Bound::Included(1u8));
+    assert_roundtrip_eq(Bound::Excluded(1u8));
+    assert_roundtrip_eq(Bound::<u8>::Unbounded);
+    assert_roundtrip_eq(Duration::new(5, 123_456_789));
+    assert_roundtrip_eq(Duration::MAX);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn decode_collections() {
+    use alloc::{
+        borrow::Cow,
+        boxed::Box,
+        collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque},
+        rc::Rc,
+        string::String,
+        sync::Arc,
+        vec,
+        vec::Vec,
+    };
+
+    assert_roundtrip_eq(String::from("příklad"));
+    assert_roundtrip_eq(Box::<str>::from("příklad"));
+    assert_roundtrip_eq(vec![1u32, 2, 3]);
+    assert_roundtrip_eq(Vec::<u8>::new());
+    assert_roundtrip_eq(vec![String::from("a"), String::from("bc")]);
+    assert_roundtrip_eq(Box::new(5u8));
+    assert_roundtrip_eq(Box::<[u8]>::from([1, 2]));
+    assert_roundtrip_eq(Rc::new(5u8));
+    assert_roundtrip_eq(Arc::new(5u8));
+    assert_roundtrip_eq(Cow::<u8>::Owned(5));
+    assert_roundtrip_eq(VecDeque::from([1u8, 2, 3]));
+    assert_roundtrip_eq(LinkedList::from([1u8, 2, 3]));
+    assert_roundtrip_eq(BTreeSet::from([3u8, 1, 2]));
+    assert_roundtrip_eq(BTreeMap::from([(2u8, 'b'), (1u8, 'a')]));
+    assert_roundtrip(BinaryHeap::from([3u8, 1, 2, 1]));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn decode_std_only_types() {
+    use std::{
+        collections::{HashMap, HashSet},
+        ffi::CString,
+        net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
+    };
+
+    assert_roundtrip_eq(CString::new("příklad").unwrap());
+    assert_roundtrip_eq(HashSet::from([3u8, 1, 2]));
+    assert_roundtrip_eq(HashMap::from([(2u8, 'b'), (1u8, 'a')]));
+    assert_roundtrip_eq(Ipv4Addr::new(127, 0, 0, 1));
+    assert_roundtrip_eq(Ipv6Addr::LOCALHOST);
+    assert_roundtrip_eq(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)));
+    assert_roundtrip_eq(IpAddr::V6(Ipv6Addr::LOCALHOST));
+    assert_roundtrip_eq(SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 80)));
+    assert_roundtrip_eq(SocketAddr::V6(SocketAddrV6::new(
+        Ipv6Addr::LOCALHOST,
+        443,
+        5,
+        7,
+    )));
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "os"))]
+fn decode_system_time() {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    assert_roundtrip_eq(SystemTime::now());
+    assert_roundtrip_eq(UNIX_EPOCH);
+    assert_roundtrip_eq(UNIX_EPOCH - Duration::from_secs(5));
+}
+
+#[test]
+fn decode_errors() {
+    assert_error::<u32>(&[1, 2, 3], DecodeError::UnexpectedEnd);
+    assert_error::<u8>(&[1, 2], DecodeError::TrailingBytes);
+    assert_error::<bool>(&[2], DecodeError::InvalidValue);
+    assert_error::<char>(&[0, 0xd8, 0, 0], DecodeError::InvalidValue);
+    assert_error::<NonZeroU8>(&[0], DecodeError::InvalidValue);
+    assert_error::<Option<u8>>(&[2], DecodeError::InvalidValue);
+    assert_error::<Option<u8>>(&[0], DecodeError::UnexpectedEnd);
+
+    // Trailing zero digits and digits that don't fit into a usize
+    assert_error::<usize>(&[0x80, 0x00], DecodeError::InvalidValue);
+    assert_error::<usize>(&[0xff; 11], DecodeError::InvalidValue);
+    assert_error::<usize>(&[0x80], DecodeError::UnexpectedEnd);
+
+    // Durations whose number of seconds doesn't fit into a u64
+    assert_error::<Duration>(&[0xff; 16], DecodeError::InvalidValue);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn decode_collection_errors() {
+    use alloc::{
+        collections::{BTreeMap, BTreeSet},
+        string::String,
+        vec::Vec,
+    };
+
+    assert_error::<String>(&[2, 0xc5], DecodeError::UnexpectedEnd);
+    assert_error::<String>(&[1, 0xc5], DecodeError::InvalidValue);
+    assert_error::<Vec<u8>>(&[0xff, 0xff, 0xff, 0xff, 0x0f], DecodeError::UnexpectedEnd);
+
+    // Unordered collections are always encoded sorted, without duplicates
+    assert_error::<BTreeSet<u8>>(&[2, 2, 1], DecodeError::InvalidValue);
+    assert_error::<BTreeSet<u8>>(&[2, 1, 1], DecodeError::InvalidValue);
+    assert_error::<BTreeMap<u8, u8>>(&[2, 2, 0, 1, 0], DecodeError::InvalidValue);
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_decode_struct() {
+    #[derive(Fingerprint, FingerprintDecode, PartialEq, Debug)]
+    struct Unit;
+
+    #[derive(Fingerprint, FingerprintDecode, PartialEq, Debug)]
+    struct Tuple(u8, i16);
+
+    #[derive(Fingerprint, FingerprintDecode, PartialEq, Debug)]
+    struct Named<T> {
+        a: T,
+        b: [u8; 2],
+    }
+
+    assert_roundtrip_eq(Unit);
+    assert_roundtrip_eq(Tuple(1, -2));
+    assert_roundtrip_eq(Named { a: 'x', b: [1, 2] });
+    assert_roundtrip_eq(Named {
+        a: Named { a: (), b: [3, 4] },
+        b: [1, 2],
+    });
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_decode_attributes() {
+    #[derive(Fingerprint, FingerprintDecode, PartialEq, Debug)]
+    #[fingerprint(domain, version = 3, named_fields, sorted_fields)]
+    struct Config {
+        #[fingerprint(rename = "z")]
+        a: u8,
+        b: u16,
+        #[fingerprint(skip)]
+        cache: Option<u8>,
+        #[fingerprint(skip_if_default)]
+        c: u32,
+        #[fingerprint(canonical_float)]
+        d: f64,
+    }
+
+    let config = Config {
+        a: 1,
+        b: 2,
+        cache: None,
+        c: 0,
+        d: 1.5,
+    };
+    assert_roundtrip_eq(config);
+
+    let config = Config {
+        a: 1,
+        b: 2,
+        cache: None,
+        c: 3,
+        d: 1.5,
+    };
+    assert_roundtrip_eq(config);
+
+    // Skipped fields are set to their default value
+    let config = Config {
+        a: 1,
+        b: 2,
+        cache: Some(5),
+        c: 3,
+        d: 1.5,
+    };
+    let decoded: Config = decode_from_slice(encode(&config).as_ref()).unwrap();
+    assert_eq!(decoded.cache, None);
+
+    // The version is checked
+    #[derive(Fingerprint)]
+    #[fingerprint(domain = "Config", version = 2, named_fields)]
+    struct Old {
+        b: u16,
+        d: f64,
+        z: u8,
+    }
+
+    let old = encode(&Old { b: 2, d: 1.5, z: 1 });
+    assert_error::<Config>(old.as_ref(), DecodeError::InvalidValue);
+}
+
+#[test]
+#[cfg(all(feature = "derive", feature = "alloc"))]
+fn derive_decode_skip_if_default() {
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Fingerprint, FingerprintDecode, PartialEq, Debug)]
+    struct Struct {
+        #[fingerprint(skip_if_default)]
+        b: u8,
+        c: (u8, Vec<u8>),
+    }
+
+    // The value after a left out field can start like the name of that field
+    assert_roundtrip_eq(Struct {
+        b: 7,
+        c: (9, vec![0xaa; 95]),
+    });
+    assert_roundtrip_eq(Struct {
+        b: 0,
+        c: (1, [&[7, 9, 95][..], &[0xaa; 95]].concat()),
+    });
+
+    #[derive(Fingerprint, FingerprintDecode, PartialEq, Debug)]
+    enum Enum {
+        A(
+            #[fingerprint(skip_if_default)] u8,
+            #[fingerprint(skip_if_default)] u8,
+        ),
+    }
+
+    assert_roundtrip_eq(Enum::A(0, 0));
+    assert_roundtrip_eq(Enum::A(1, 0));
+    assert_roundtrip_eq(Enum::A(0, 2));
+    assert_roundtrip_eq(Enum::A(1, 2));
+
+    // The number of present fields is checked
+    assert_error::<Enum>(&[0, 1, 1, b'0', 1, 1, b'1', 2], DecodeError::TrailingBytes);
+    assert_error::<Enum>(&[0, 2, 1, b'1', 2], DecodeError::InvalidValue);
+    assert_error::<Struct>(&[0], DecodeError::InvalidValue);
+
+    // Present fields can't be equal to their default value
+    assert_error::<Enum>(&[0, 1, 1, b'0', 0], DecodeError::InvalidValue);
+}
+
+#[test]
+#[cfg(all(feature = "derive", feature = "alloc"))]
+fn derive_decode_bound() {
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Fingerprint, FingerprintDecode, PartialEq, Debug)]
+    #[fingerprint(bound = "T: Fingerprint")]
+    struct Node<T> {
+        value: T,
+        children: Vec<Node<T>>,
+    }
+
+    assert_roundtrip_eq(Node {
+        value: 1u8,
+        children: vec![Node {
+            value: 2u8,
+            children: vec![],
+        }],
+    });
+
+    #[derive(Fingerprint, FingerprintDecode, PartialEq, Debug)]
+    enum Tree<T, L> {
+        Leaf(L),
+        Branch(T, #[fingerprint(bound = "")] Vec<Tree<T, L>>),
+    }
+
+    assert_roundtrip_eq(Tree::Branch('a', vec![Tree::Leaf(1u16), Tree::Leaf(2u16)]));
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_decode_enum() {
+    const C: u8 = 10;
+
+    #[derive(Fingerprint, FingerprintDecode, PartialEq, Debug)]
+    #[repr(u8)]
+    enum Enum {
+        A,
+        B(u16, char),
+        #[fingerprint(tag = 5)]
+        C {
+            c: bool,
+        },
+        D = C,
+    }
+
+    assert_roundtrip_eq(Enum::A);
+    assert_roundtrip_eq(Enum::B(1, 'b'));
+    assert_roundtrip_eq(Enum::C { c: true });
+    assert_roundtrip_eq(Enum::D);
+    assert_error::<Enum>(&[2], DecodeError::InvalidValue);
+    assert_error::<Enum>(&[], DecodeError::UnexpectedEnd);
+
+    #[derive(Fingerprint, FingerprintDecode, PartialEq, Debug)]
+    #[fingerprint(tag = "name")]
+    enum Named {
+        #[fingerprint(tag = "first")]
+        A(u8),
+        B,
+    }
+
+    assert_roundtrip_eq(Named::A(1));
+    assert_roundtrip_eq(Named::B);
+    assert_error::<Named>(&[1, b'A'], DecodeError::InvalidValue);
+}
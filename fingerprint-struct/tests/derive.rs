@@ -152,3 +152,908 @@ fn derive_struct_generic_as_field_generic() {
 
     assert_same_fingerprint(Struct(Some(1337u32)), Some(1337u32));
 }
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_struct_fields_skip() {
+    #[derive(Fingerprint)]
+    #[allow(dead_code)]
+    struct Struct {
+        a: u8,
+        #[fingerprint(skip)]
+        b: u16,
+        c: u32,
+    }
+
+    assert_same_fingerprint(Struct { a: 1, b: 2, c: 3 }, (1u8, 3u32));
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_struct_tuple_skip() {
+    #[derive(Fingerprint)]
+    #[allow(dead_code)]
+    struct Struct(#[fingerprint(skip)] u8, u16, #[fingerprint(skip)] u32);
+
+    assert_same_fingerprint(Struct(1, 2, 3), 2u16);
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_enum_fields_skip() {
+    #[derive(Fingerprint)]
+    #[allow(dead_code)]
+    enum Enum {
+        A {
+            #[fingerprint(skip)]
+            a: u8,
+            b: u16,
+        },
+        B {
+            #[fingerprint(skip)]
+            a: u32,
+        },
+    }
+
+    assert_same_fingerprint(Enum::A { a: 1, b: 2 }, (0isize, 2u16));
+    assert_same_fingerprint(Enum::B { a: 1 }, 1isize);
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_enum_tuple_skip() {
+    #[derive(Fingerprint)]
+    #[allow(dead_code)]
+    enum Enum {
+        A(u8, #[fingerprint(skip)] u16),
+        B(#[fingerprint(skip)] u32, i64),
+    }
+
+    assert_same_fingerprint(Enum::A(1, 2), (0isize, 1u8));
+    assert_same_fingerprint(Enum::B(1, 2), (1isize, 2i64));
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_struct_skip_unfingerprintable() {
+    struct Unfingerprintable;
+
+    #[derive(Fingerprint)]
+    #[allow(dead_code)]
+    struct Struct<T> {
+        a: u8,
+        #[fingerprint(skip)]
+        b: T,
+    }
+
+    assert_same_fingerprint(
+        Struct {
+            a: 1,
+            b: Unfingerprintable,
+        },
+        1u8,
+    );
+}
+
+#[cfg(feature = "derive")]
+mod foreign {
+    use digest::Update;
+    use fingerprint_struct::Fingerprint;
+
+    pub struct Point {
+        pub x: i32,
+        pub y: i32,
+    }
+
+    pub fn fingerprint_point<U: Update>(point: &Point, hasher: &mut U) {
+        point.x.fingerprint(hasher);
+        point.y.fingerprint(hasher);
+    }
+
+    pub fn fingerprint_reversed<U: Update>(value: &u32, hasher: &mut U) {
+        value.reverse_bits().fingerprint(hasher);
+    }
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_struct_fields_with() {
+    use foreign::Point;
+
+    #[derive(Fingerprint)]
+    struct Struct {
+        a: u8,
+        #[fingerprint(with = "foreign::fingerprint_point")]
+        b: Point,
+        #[fingerprint(with = "foreign::fingerprint_reversed")]
+        c: u32,
+    }
+
+    assert_same_fingerprint(
+        Struct {
+            a: 1,
+            b: Point { x: 2, y: 3 },
+            c: 4,
+        },
+        (1u8, 2i32, 3i32, 4u32.reverse_bits()),
+    );
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_struct_tuple_with() {
+    use foreign::Point;
+
+    #[derive(Fingerprint)]
+    struct Struct(
+        u8,
+        #[fingerprint(with = "foreign::fingerprint_point")] Point,
+    );
+
+    assert_same_fingerprint(Struct(1, Point { x: 2, y: 3 }), (1u8, 2i32, 3i32));
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_enum_with() {
+    use foreign::Point;
+
+    #[derive(Fingerprint)]
+    enum Enum {
+        A(#[fingerprint(with = "foreign::fingerprint_point")] Point),
+        B {
+            #[fingerprint(with = "foreign::fingerprint_reversed")]
+            a: u32,
+        },
+    }
+
+    assert_same_fingerprint(Enum::A(Point { x: 2, y: 3 }), (0isize, 2i32, 3i32));
+    assert_same_fingerprint(Enum::B { a: 4 }, (1isize, 4u32.reverse_bits()));
+}
+
+#[cfg(feature = "derive")]
+mod wrappers {
+    pub struct Uuid(pub u64, pub u64);
+
+    impl From<&Uuid> for [u8; 16] {
+        fn from(uuid: &Uuid) -> Self {
+            let mut bytes = [0; 16];
+            bytes[..8].copy_from_slice(&uuid.0.to_be_bytes());
+            bytes[8..].copy_from_slice(&uuid.1.to_be_bytes());
+            bytes
+        }
+    }
+
+    pub struct Label(pub &'static str);
+
+    impl From<&Label> for &'static str {
+        fn from(label: &Label) -> Self {
+            label.0
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_struct_fields_as() {
+    use wrappers::{Label, Uuid};
+
+    #[derive(Fingerprint)]
+    struct Wrapped {
+        #[fingerprint(as = "[u8; 16]")]
+        id: Uuid,
+        #[fingerprint(as = "&'static str")]
+        label: Label,
+    }
+
+    #[derive(Fingerprint)]
+    struct Canonical {
+        id: [u8; 16],
+        label: &'static str,
+    }
+
+    let mut id = [0; 16];
+    id[7] = 1;
+    id[15] = 2;
+
+    assert_same_fingerprint(
+        Wrapped {
+            id: Uuid(1, 2),
+            label: Label("label"),
+        },
+        Canonical { id, label: "label" },
+    );
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_enum_as() {
+    use wrappers::{Label, Uuid};
+
+    #[derive(Fingerprint)]
+    enum Wrapped {
+        A(#[fingerprint(as = "&'static str")] Label),
+        B {
+            #[fingerprint(as = "[u8; 16]")]
+            id: Uuid,
+        },
+    }
+
+    #[derive(Fingerprint)]
+    enum Canonical {
+        A(&'static str),
+        B { id: [u8; 16] },
+    }
+
+    let mut id = [0; 16];
+    id[7] = 3;
+
+    assert_same_fingerprint(Wrapped::A(Label("label")), Canonical::A("label"));
+    assert_same_fingerprint(Wrapped::B { id: Uuid(3, 0) }, Canonical::B { id });
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_struct_version() {
+    #[derive(Fingerprint)]
+    #[fingerprint(version = 3)]
+    struct Struct {
+        a: u8,
+        b: u16,
+    }
+
+    assert_same_fingerprint(Struct { a: 1, b: 2 }, (3u32, 1u8, 2u16));
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_struct_unit_version() {
+    #[derive(Fingerprint)]
+    #[fingerprint(version = 0xdeadbeef)]
+    struct Struct;
+
+    assert_same_fingerprint(Struct, 0xdeadbeefu32);
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_enum_version() {
+    #[derive(Fingerprint)]
+    #[fingerprint(version = 7)]
+    enum Enum {
+        A(u8),
+        B { a: u16 },
+    }
+
+    assert_same_fingerprint(Enum::A(1), (7u32, 0isize, 1u8));
+    assert_same_fingerprint(Enum::B { a: 2 }, (7u32, 1isize, 2u16));
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_struct_domain() {
+    #[derive(Fingerprint)]
+    #[fingerprint(domain)]
+    struct UserId(u64);
+
+    #[derive(Fingerprint)]
+    #[fingerprint(domain)]
+    struct OrderId(u64);
+
+    assert_same_fingerprint(UserId(42), ("UserId", 42u64));
+    assert_same_fingerprint(OrderId(42), ("OrderId", 42u64));
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_struct_domain_custom() {
+    #[derive(Fingerprint)]
+    #[fingerprint(domain = "com.example.user")]
+    struct User {
+        id: u64,
+    }
+
+    assert_same_fingerprint(User { id: 42 }, ("com.example.user", 42u64));
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_enum_domain() {
+    #[derive(Fingerprint)]
+    #[fingerprint(domain)]
+    enum Enum {
+        A,
+        B(u8),
+    }
+
+    assert_same_fingerprint(Enum::A, ("Enum", 0isize));
+    assert_same_fingerprint(Enum::B(1), ("Enum", 1isize, 1u8));
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_struct_domain_version() {
+    #[derive(Fingerprint)]
+    #[fingerprint(version = 2, domain)]
+    struct Struct(u8);
+
+    assert_same_fingerprint(Struct(1), ("Struct", 2u32, 1u8));
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_enum_tag_name() {
+    #[derive(Fingerprint)]
+    #[fingerprint(tag = "name")]
+    enum Enum {
+        A,
+        B(u8),
+        C { a: u16 },
+    }
+
+    assert_same_fingerprint(Enum::A, "A");
+    assert_same_fingerprint(Enum::B(1), ("B", 1u8));
+    assert_same_fingerprint(Enum::C { a: 2 }, ("C", 2u16));
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_enum_tag_name_reorder() {
+    #[derive(Fingerprint)]
+    #[fingerprint(tag = "name")]
+    enum Before {
+        A(u8),
+        C(u8),
+    }
+
+    #[derive(Fingerprint)]
+    #[fingerprint(tag = "name")]
+    enum After {
+        B,
+        C(u8),
+        A(u8),
+    }
+
+    assert_same_fingerprint(Before::A(1), After::A(1));
+    assert_same_fingerprint(Before::C(2), After::C(2));
+    assert_same_fingerprint(After::B, "B");
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_enum_tag_name_override() {
+    #[derive(Fingerprint)]
+    #[fingerprint(tag = "name")]
+    enum Enum {
+        #[fingerprint(tag = "Legacy")]
+        A,
+        B,
+    }
+
+    assert_same_fingerprint(Enum::A, "Legacy");
+    assert_same_fingerprint(Enum::B, "B");
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_enum_tag_override() {
+    #[repr(u8)]
+    #[derive(Fingerprint)]
+    enum Enum {
+        A,
+        #[fingerprint(tag = 17)]
+        B,
+        C,
+    }
+
+    assert_eq!(Enum::B as u8, 1);
+    assert_same_fingerprint(Enum::A, 0u8);
+    assert_same_fingerprint(Enum::B, 17u8);
+    assert_same_fingerprint(Enum::C, 2u8);
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_enum_tag_override_const_discriminant() {
+    const BASE: isize = 10;
+
+    #[derive(Fingerprint)]
+    enum Enum {
+        A = BASE,
+        #[fingerprint(tag = -1)]
+        B,
+    }
+
+    assert_same_fingerprint(Enum::A, 10isize);
+    assert_same_fingerprint(Enum::B, -1isize);
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_struct_named_fields() {
+    #[derive(Fingerprint)]
+    #[fingerprint(named_fields)]
+    struct Struct {
+        a: u8,
+        #[fingerprint(rename = "renamed")]
+        b: u16,
+        #[fingerprint(skip)]
+        #[allow(dead_code)]
+        c: u32,
+    }
+
+    assert_same_fingerprint(Struct { a: 1, b: 2, c: 3 }, ("a", 1u8, "renamed", 2u16));
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_struct_sorted_fields() {
+    #[derive(Fingerprint)]
+    #[fingerprint(sorted_fields)]
+    struct Before {
+        b: u16,
+        a: u8,
+    }
+
+    #[derive(Fingerprint)]
+    #[fingerprint(sorted_fields)]
+    struct After {
+        a: u8,
+        b: u16,
+    }
+
+    assert_same_fingerprint(Before { a: 1, b: 2 }, After { a: 1, b: 2 });
+    assert_same_fingerprint(Before { a: 1, b: 2 }, (1u8, 2u16));
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_struct_named_sorted_fields() {
+    #[derive(Fingerprint)]
+    #[fingerprint(named_fields, sorted_fields)]
+    struct Struct {
+        c: u8,
+        #[fingerprint(rename = "a")]
+        b: u16,
+        #[fingerprint(rename = "b")]
+        a: u32,
+    }
+
+    assert_same_fingerprint(
+        Struct { c: 1, b: 2, a: 3 },
+        ("a", 2u16, "b", 3u32, "c", 1u8),
+    );
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_enum_named_fields() {
+    #[derive(Fingerprint)]
+    #[fingerprint(named_fields, sorted_fields)]
+    enum Enum {
+        A { b: u8, a: u16 },
+        B(u8, u16),
+    }
+
+    assert_same_fingerprint(Enum::A { a: 1, b: 2 }, (0isize, "a", 1u16, "b", 2u8));
+    assert_same_fingerprint(Enum::B(1, 2), (1isize, 1u8, 2u16));
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_struct_skip_if_default() {
+    #[derive(Fingerprint)]
+    struct Old {
+        a: u8,
+        #[fingerprint(skip_if_default)]
+        b: Option<u16>,
+    }
+
+    #[derive(Fingerprint)]
+    struct New {
+        a: u8,
+        #[fingerprint(skip_if_default)]
+        b: Option<u16>,
+        #[fingerprint(skip_if_default)]
+        c: u32,
+    }
+
+    assert_same_fingerprint(Old { a: 1, b: None }, (1usize, "a", 1u8));
+    assert_same_fingerprint(
+        Old { a: 1, b: Some(2) },
+        (2usize, "a", 1u8, "b", Some(2u16)),
+    );
+
+    assert_same_fingerprint(
+        New {
+            a: 1,
+            b: None,
+            c: 0,
+        },
+        Old { a: 1, b: None },
+    );
+    assert_same_fingerprint(
+        New {
+            a: 1,
+            b: Some(2),
+            c: 0,
+        },
+        Old { a: 1, b: Some(2) },
+    );
+    assert_same_fingerprint(
+        New {
+            a: 1,
+            b: None,
+            c: 3,
+        },
+        (2usize, "a", 1u8, "c", 3u32),
+    );
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_struct_skip_if() {
+    fn is_zero(value: &u32) -> bool {
+        *value == 0
+    }
+
+    #[derive(Fingerprint)]
+    struct Struct(u8, #[fingerprint(skip_if = "is_zero")] u32);
+
+    assert_same_fingerprint(Struct(1, 0), (1usize, "0", 1u8));
+    assert_same_fingerprint(Struct(1, 2), (2usize, "0", 1u8, "1", 2u32));
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_enum_skip_if_default_named_fields() {
+    #[derive(Fingerprint)]
+    #[fingerprint(named_fields)]
+    enum Enum {
+        A {
+            a: u8,
+            #[fingerprint(skip_if_default, rename = "c")]
+            b: u16,
+        },
+        B(u8),
+    }
+
+    assert_same_fingerprint(Enum::A { a: 1, b: 0 }, (0isize, 1usize, "a", 1u8));
+    assert_same_fingerprint(
+        Enum::A { a: 1, b: 2 },
+        (0isize, 2usize, "a", 1u8, "c", 2u16),
+    );
+    assert_same_fingerprint(Enum::B(1), (1isize, 1u8));
+}
+
+#[test]
+#[cfg(all(feature = "derive", feature = "alloc"))]
+fn derive_skip_if_default_injective() {
+    extern crate alloc;
+
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Fingerprint)]
+    struct Struct {
+        #[fingerprint(skip_if_default)]
+        b: u8,
+        c: (u8, Vec<u8>),
+    }
+
+    #[derive(Fingerprint)]
+    struct Trailing {
+        a: u8,
+        #[fingerprint(skip_if_default)]
+        b: u8,
+    }
+
+    #[derive(Fingerprint)]
+    struct Leading {
+        #[fingerprint(skip_if_default)]
+        b: u8,
+    }
+
+    fn encode<T: Fingerprint>(value: T) -> MockDigest {
+        let mut hasher = MockDigest::default();
+        value.fingerprint(&mut hasher);
+        hasher
+    }
+
+    // The field after a skipped one can't be mistaken for the skipped field, even if its bytes
+    // start like the name of the skipped field
+    let present = Struct {
+        b: 7,
+        c: (9, vec![0xaa; 95]),
+    };
+    let absent = Struct {
+        b: 0,
+        c: (1, [&[7, 9, 95][..], &[0xaa; 95]].concat()),
+    };
+    assert_eq!(absent.c.1.len(), b'b' as usize);
+    assert_ne!(encode(present).as_ref(), encode(absent).as_ref());
+
+    // A struct that ends with a skipped field can't be mistaken for one that doesn't
+    let present = (Trailing { a: 1, b: 5 }, Leading { b: 0 });
+    let absent = (Trailing { a: 1, b: 0 }, Leading { b: 5 });
+    assert_ne!(encode(present).as_ref(), encode(absent).as_ref());
+}
+
+#[test]
+#[cfg(all(feature = "derive", feature = "alloc"))]
+fn derive_struct_bound() {
+    extern crate alloc;
+
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Fingerprint)]
+    #[fingerprint(bound = "T: Fingerprint")]
+    struct Node<T> {
+        value: T,
+        children: Vec<Node<T>>,
+    }
+
+    assert_same_fingerprint(
+        Node {
+            value: 1u8,
+            children: vec![Node {
+                value: 2u8,
+                children: vec![],
+            }],
+        },
+        (1u8, 1usize, 2u8, 0usize),
+    );
+}
+
+#[test]
+#[cfg(all(feature = "derive", feature = "alloc"))]
+fn derive_struct_field_bound() {
+    extern crate alloc;
+
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Fingerprint)]
+    enum Tree<T> {
+        Leaf(T),
+        Branch(#[fingerprint(bound = "")] Vec<Tree<T>>),
+    }
+
+    assert_same_fingerprint(
+        Tree::Branch(vec![Tree::Leaf(1u8)]),
+        (1isize, 1usize, 0isize, 1u8),
+    );
+}
+
+#[cfg(feature = "derive")]
+mod facade {
+    pub use fingerprint_struct;
+}
+
+#[cfg(feature = "derive")]
+mod hygiene {
+    // Neither the Fingerprint trait nor digest are in scope here
+    #[derive(fingerprint_struct::Fingerprint)]
+    pub struct Struct {
+        pub a: u8,
+        pub b: Option<u16>,
+    }
+
+    #[derive(fingerprint_struct::Fingerprint)]
+    #[fingerprint(version = 1)]
+    pub enum Enum {
+        A(u8),
+        B { b: u16 },
+    }
+
+    #[derive(crate::facade::fingerprint_struct::Fingerprint)]
+    #[fingerprint(crate = "crate::facade::fingerprint_struct")]
+    pub struct Reexported(pub u32);
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_hygiene() {
+    use hygiene::{Enum, Struct};
+
+    assert_same_fingerprint(Struct { a: 1, b: Some(2) }, (1u8, Some(2u16)));
+    assert_same_fingerprint(Enum::A(1), (1u32, 0isize, 1u8));
+    assert_same_fingerprint(Enum::B { b: 2 }, (1u32, 1isize, 2u16));
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_struct_crate() {
+    assert_same_fingerprint(hygiene::Reexported(1), 1u32);
+}
+
+#[cfg(feature = "derive")]
+mod dep {
+    pub struct Config {
+        pub name: &'static str,
+        pub retries: u32,
+        pub verbose: bool,
+    }
+
+    pub struct Pair<T>(pub T, pub T);
+
+    pub enum Shape {
+        Square(u32),
+        Rectangle { width: u32, height: u32 },
+        Empty,
+    }
+}
+
+#[cfg(feature = "derive")]
+mod remote {
+    use fingerprint_struct::Fingerprint;
+
+    use crate::dep;
+
+    #[allow(dead_code)]
+    #[derive(Fingerprint)]
+    #[fingerprint(remote = "dep::Config")]
+    pub struct ConfigDef {
+        pub name: &'static str,
+        pub retries: u32,
+        #[fingerprint(skip)]
+        pub verbose: bool,
+    }
+
+    #[derive(Fingerprint)]
+    #[fingerprint(remote = "dep::Pair<T>")]
+    pub struct PairDef<T>(pub T, pub T);
+
+    #[allow(dead_code)]
+    #[derive(Fingerprint)]
+    #[fingerprint(remote = "dep::Shape", domain)]
+    pub enum ShapeDef {
+        Square(u32),
+        Rectangle { width: u32, height: u32 },
+        Empty,
+    }
+
+    #[derive(Fingerprint)]
+    pub struct Wrapper(#[fingerprint(with = "ConfigDef::fingerprint")] pub dep::Config);
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_struct_remote() {
+    use dep::{Config, Pair};
+    use remote::{PairDef, Wrapper};
+
+    let config = Config {
+        name: "test",
+        retries: 3,
+        verbose: true,
+    };
+
+    assert_same_fingerprint(Wrapper(config), ("test", 3u32));
+
+    let mut hasher = MockDigest::default();
+    PairDef::fingerprint(&Pair(1u8, 2u8), &mut hasher);
+    assert_eq!(hasher.as_ref(), [1, 2]);
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_enum_remote() {
+    use dep::Shape;
+    use remote::ShapeDef;
+
+    let fingerprint = |shape: Shape| {
+        let mut hasher = MockDigest::default();
+        ShapeDef::fingerprint(&shape, &mut hasher);
+        hasher
+    };
+
+    let mut expected = MockDigest::default();
+    ("Shape", 1isize, 2u32, 3u32).fingerprint(&mut expected);
+
+    assert_eq!(
+        fingerprint(Shape::Rectangle {
+            width: 2,
+            height: 3
+        })
+        .as_ref(),
+        expected.as_ref()
+    );
+
+    let mut expected = MockDigest::default();
+    ("Shape", 0isize, 1u32).fingerprint(&mut expected);
+    assert_eq!(fingerprint(Shape::Square(1)).as_ref(), expected.as_ref());
+
+    let mut expected = MockDigest::default();
+    ("Shape", 2isize).fingerprint(&mut expected);
+    assert_eq!(fingerprint(Shape::Empty).as_ref(), expected.as_ref());
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_union_discriminant() {
+    #[repr(C)]
+    #[derive(Clone, Copy, Fingerprint)]
+    struct Key {
+        kind: u32,
+        code: u32,
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Fingerprint)]
+    struct Mouse {
+        kind: u32,
+        x: i16,
+        y: i16,
+    }
+
+    #[derive(Fingerprint)]
+    #[fingerprint(unsafe_discriminant = "unsafe { self.kind }")]
+    union Event {
+        #[fingerprint(skip)]
+        kind: u32,
+        #[fingerprint(when = "1")]
+        key: Key,
+        #[fingerprint(when = "2")]
+        mouse: Mouse,
+        #[fingerprint(when = "_")]
+        raw: [u32; 2],
+    }
+
+    let key = Event {
+        key: Key { kind: 1, code: 5 },
+    };
+    let mouse = Event {
+        mouse: Mouse {
+            kind: 2,
+            x: 3,
+            y: -4,
+        },
+    };
+    let raw = Event { raw: [7, 8] };
+
+    assert_same_fingerprint(key, (1isize, 1u32, 5u32));
+    assert_same_fingerprint(mouse, (2isize, 2u32, 3i16, -4i16));
+    assert_same_fingerprint(raw, (3isize, [7u32, 8u32]));
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_union_bytes() {
+    #[derive(Fingerprint)]
+    #[fingerprint(bytes)]
+    union Number {
+        int: u32,
+        float: f32,
+        bytes: [u8; 4],
+    }
+
+    // The bytes are read like the first field, in little-endian byte order
+    assert_same_fingerprint(Number { int: 0x01020304 }, 0x01020304u32);
+    assert_same_fingerprint(Number { float: 1.5 }, 1.5f32.to_bits());
+    assert_same_fingerprint(
+        Number {
+            bytes: [1, 2, 3, 4],
+        },
+        u32::from_ne_bytes([1, 2, 3, 4]),
+    );
+
+    #[derive(Fingerprint)]
+    #[fingerprint(bytes)]
+    union Halves {
+        halves: [u16; 2],
+        int: u32,
+    }
+
+    assert_same_fingerprint(Halves { halves: [1, 2] }, [1u16, 2]);
+    assert_same_fingerprint(Halves { int: u32::MAX }, [u16::MAX; 2]);
+
+    #[derive(Fingerprint)]
+    #[fingerprint(bytes)]
+    union Generic<T: Copy> {
+        value: T,
+        chars: [char; 2],
+    }
+
+    assert_same_fingerprint(Generic { value: u64::MAX }, u64::MAX);
+    assert_same_fingerprint(Generic { value: [1u32, 2] }, [1u32, 2]);
+    assert_same_fingerprint(Generic::<u64> { chars: ['\0'; 2] }, 0u64);
+}
@@ -0,0 +1,78 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use fingerprint_struct::{DynFingerprint, Fingerprint};
+use mock_digest::MockDigest;
+
+fn assert_same_fingerprint<T1: Fingerprint + ?Sized, T2: Fingerprint + ?Sized>(a: &T1, b: &T2) {
+    let mut hasher_a = MockDigest::default();
+    let mut hasher_b = MockDigest::default();
+
+    a.fingerprint(&mut hasher_a);
+    b.fingerprint(&mut hasher_b);
+
+    assert_eq!(hasher_a.as_ref(), hasher_b.as_ref());
+}
+
+#[test]
+fn fingerprint_trait_object() {
+    let mut value = (300usize, "příklad");
+
+    let object: &dyn DynFingerprint = &value;
+    assert_same_fingerprint(object, &value);
+    assert_same_fingerprint(&object, &value);
+
+    let object: &(dyn DynFingerprint + Send) = &value;
+    assert_same_fingerprint(object, &value);
+    assert_same_fingerprint(&object, &value);
+
+    let object: &(dyn DynFingerprint + Sync) = &value;
+    assert_same_fingerprint(object, &value);
+    assert_same_fingerprint(&object, &value);
+
+    let object: &(dyn DynFingerprint + Send + Sync) = &value;
+    assert_same_fingerprint(object, &value);
+    assert_same_fingerprint(&object, &value);
+
+    let expected = value;
+    let object: &mut (dyn DynFingerprint + Send + Sync) = &mut value;
+    assert_same_fingerprint(&object, &expected);
+}
+
+#[test]
+fn fingerprint_nested_trait_objects() {
+    let inner: [&dyn DynFingerprint; 2] = [&1u8, &[300u16; 2]];
+    let objects: [&(dyn DynFingerprint + Sync); 2] = [&300usize, &'ž'];
+    let outer: (&dyn DynFingerprint, &dyn DynFingerprint) = (&inner, &objects);
+
+    assert_same_fingerprint(&outer, &((1u8, [300u16; 2]), (300usize, 'ž')));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn fingerprint_boxed_trait_objects() {
+    use alloc::{boxed::Box, rc::Rc, sync::Arc, vec, vec::Vec};
+
+    let objects: Vec<Box<dyn DynFingerprint>> = vec![Box::new(300usize), Box::new("")];
+    assert_same_fingerprint(&objects, &(2usize, 300usize, ""));
+
+    let object: Box<dyn DynFingerprint + Send> = Box::new(-300isize);
+    assert_same_fingerprint(&object, &-300isize);
+
+    let nested: Box<dyn DynFingerprint + Send + Sync> =
+        Box::new(vec![Box::new(1u8) as Box<dyn DynFingerprint + Send + Sync>]);
+    assert_same_fingerprint(&nested, &(1usize, 1u8));
+
+    let object: Rc<dyn DynFingerprint> = Rc::new(-300isize);
+    assert_same_fingerprint(&object, &-300isize);
+
+    let object: Rc<dyn DynFingerprint + Sync> = Rc::new("rc");
+    assert_same_fingerprint(&object, "rc");
+
+    let object: Arc<dyn DynFingerprint> = Arc::new('ž');
+    assert_same_fingerprint(&object, &'ž');
+
+    let object: Arc<dyn DynFingerprint + Send + Sync> = Arc::new(vec![300usize; 3]);
+    assert_same_fingerprint(&object, &(3usize, [300usize; 3]));
+}
@@ -0,0 +1,98 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fingerprint_struct::{encode_into, encoded_len, Fingerprint};
+use mock_digest::MockDigest;
+
+fn assert_encoding<T: Fingerprint + ?Sized>(value: &T) {
+    let mut hasher = MockDigest::default();
+    value.fingerprint(&mut hasher);
+    let expected = hasher.as_ref();
+
+    let mut buffer = [0u8; 256];
+    let mut writer = Writer {
+        buffer: &mut buffer,
+        len: 0,
+    };
+    encode_into(value, &mut writer);
+    let len = writer.len;
+
+    assert_eq!(&buffer[..len], expected);
+    assert_eq!(encoded_len(value), expected.len() as u64);
+
+    #[cfg(feature = "alloc")]
+    assert_eq!(fingerprint_struct::encode_to_vec(value), expected);
+}
+
+struct Writer<'a> {
+    buffer: &'a mut [u8],
+    len: usize,
+}
+
+impl Extend<u8> for Writer<'_> {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        for byte in iter {
+            self.buffer[self.len] = byte;
+            self.len += 1;
+        }
+    }
+}
+
+#[test]
+fn encode_primitives() {
+    assert_encoding(&0u8);
+    assert_encoding(&-1i64);
+    assert_encoding(&300usize);
+    assert_encoding(&-300isize);
+    assert_encoding(&1.5f32);
+    assert_encoding(&'ž');
+    assert_encoding(&true);
+}
+
+#[test]
+fn encode_compound_types() {
+    assert_encoding(&());
+    assert_encoding("příklad");
+    assert_encoding(&[1u16, 2, 3][..]);
+    assert_encoding(&(Some(1u8), None::<u8>, Ok::<u8, u16>(2), Err::<u8, u16>(3)));
+    assert_encoding(&[[1u32; 3]; 4]);
+}
+
+#[test]
+fn encode_known_bytes() {
+    let mut bytes = [0u8; 3];
+    let mut writer = Writer {
+        buffer: &mut bytes,
+        len: 0,
+    };
+
+    encode_into(&(1u8, 300usize), &mut writer);
+    assert_eq!(bytes, [1, 0xac, 0x02]);
+    assert_eq!(encoded_len(&(1u8, 300usize)), 3);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn encode_to_vec() {
+    extern crate alloc;
+
+    use alloc::{string::String, vec};
+    use fingerprint_struct::encode_to_vec;
+
+    assert_eq!(encode_to_vec(&vec![1u8, 2]), [2, 1, 2]);
+    assert_eq!(encode_to_vec(&String::from("a")), [1, b'a']);
+    assert_encoding(&vec![(String::from("a"), 1u64)]);
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn encode_derive() {
+    #[derive(Fingerprint)]
+    #[fingerprint(version = 1)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    assert_encoding(&Point { x: 1, y: -1 });
+    assert_eq!(encoded_len(&Point { x: 1, y: -1 }), 12);
+}
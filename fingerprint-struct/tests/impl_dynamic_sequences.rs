@@ -32,7 +32,7 @@ fn assert_same_fingerprint<A: Fingerprint, B: Fingerprint>(a: A, b: B) {
 fn fingerprint_array_slice() {
     let data: &[u8] = &[1, 2, 3, 4];
 
-    assert_same_fingerprint(data, &[4u8, 1, 2, 3, 4]);
+    assert_same_fingerprint(data, [4u8, 1, 2, 3, 4]);
 }
 
 #[test]
@@ -49,7 +49,7 @@ fn fingerprint_cstr() {
 
         assert_same_fingerprint(
             str.as_c_str(),
-            &[0x70, 0xc5, 0x99, 0xc3, 0xad, 0x6b, 0x6c, 0x61, 0x64, 0u8],
+            [0x70, 0xc5, 0x99, 0xc3, 0xad, 0x6b, 0x6c, 0x61, 0x64, 0u8],
         );
     }
 }
@@ -65,7 +65,39 @@ fn fingerprint_cstring() {
 #[test]
 #[cfg(feature = "alloc")]
 fn fingerprint_vec() {
-    assert_same_fingerprint(vec![1u8, 2u8, 3u8, 4u8], &[4u8, 1, 2, 3, 4]);
+    assert_same_fingerprint(vec![1u8, 2u8, 3u8, 4u8], [4u8, 1, 2, 3, 4]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn fingerprint_primitive_vec() {
+    assert_same_fingerprint(vec![1u32, 2, 3], (3usize, 1u32, 2u32, 3u32));
+    assert_same_fingerprint(vec![-1i64, 2], (2usize, -1i64, 2i64));
+    assert_same_fingerprint(vec![0.5f64, -0.0], (2usize, 0.5f64, -0.0f64));
+    assert_same_fingerprint(vec![true, false, true], (3usize, true, false, true));
+    assert_same_fingerprint(vec!['x', 'ž'], (2usize, 'x', 'ž'));
+}
+
+#[test]
+fn fingerprint_primitive_slice_single_update() {
+    #[derive(Default)]
+    struct CountingDigest {
+        updates: usize,
+    }
+
+    impl digest::Update for CountingDigest {
+        fn update(&mut self, _data: &[u8]) {
+            self.updates += 1;
+        }
+    }
+
+    let data: &[u16] = &[1; 100];
+    let mut hasher = CountingDigest::default();
+    data.fingerprint(&mut hasher);
+
+    // The single byte length, followed by all elements at once
+    #[cfg(target_endian = "little")]
+    assert_eq!(hasher.updates, 2);
 }
 
 #[test]
@@ -78,6 +110,13 @@ fn fingerprint_linked_list() {
 #[cfg(feature = "alloc")]
 fn fingerprint_vec_deque() {
     assert_same_fingerprint(VecDeque::from([1, 2, 3, 4]), vec![1, 2, 3, 4]);
+
+    // Elements wrap around the end of the buffer, so they are stored in two slices
+    let mut deque = VecDeque::with_capacity(4);
+    deque.extend([0u32, 0, 1, 2]);
+    deque.drain(..2);
+    deque.extend([3, 4]);
+    assert_same_fingerprint(deque, vec![1u32, 2, 3, 4]);
 }
 
 #[test]
@@ -16,7 +16,17 @@ fn fingerprint_u8_array() {
 
 #[test]
 fn fingerprint_u32_array() {
-    assert_fingerprint(&[10, 20], &[10, 0, 0, 0, 20, 0, 0, 0]);
+    assert_fingerprint([10, 20], &[10, 0, 0, 0, 20, 0, 0, 0]);
+}
+
+#[test]
+fn fingerprint_primitive_arrays() {
+    assert_fingerprint([-1i16, 2], &[0xff, 0xff, 2, 0]);
+    assert_fingerprint([1.0f32, -2.0], &[0, 0, 0x80, 0x3f, 0, 0, 0, 0xc0]);
+    assert_fingerprint([true, false], &[1, 0]);
+    assert_fingerprint(['a', 'ř'], &[0x61, 0, 0, 0, 0x59, 0x01, 0, 0]);
+    assert_fingerprint([300u128], &300u128.to_le_bytes());
+    assert_fingerprint([1usize, 300], &[1, 0xac, 0x02]);
 }
 
 #[test]
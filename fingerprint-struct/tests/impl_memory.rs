@@ -20,11 +20,13 @@ fn assert_same_fingerprint<A: Fingerprint, B: Fingerprint>(a: A, b: B) {
 }
 
 #[test]
+#[allow(clippy::needless_borrows_for_generic_args)]
 fn fingerprint_ref() {
     assert_same_fingerprint(&42, 42);
 }
 
 #[test]
+#[allow(clippy::needless_borrows_for_generic_args)]
 fn fingerprint_ref_mut() {
     assert_same_fingerprint(&mut 42, 42);
 }
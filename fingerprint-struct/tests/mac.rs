@@ -0,0 +1,74 @@
+#![cfg(feature = "mac")]
+
+use blake2::Blake2bMac512;
+use fingerprint_struct::{fingerprint_mac, verify_mac};
+use hmac::Hmac;
+use sha2::Sha256;
+
+#[test]
+fn fingerprint_hmac() {
+    let key = [0x0bu8; 20];
+
+    let tag = fingerprint_mac::<Hmac<Sha256>>(&key, *b"Hi There").unwrap();
+    let tag = tag.into_bytes();
+    assert_eq!(
+        hex::encode(tag),
+        "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7"
+    );
+
+    assert_eq!(verify_mac::<Hmac<Sha256>>(&key, *b"Hi There", &tag), Ok(()));
+    assert!(verify_mac::<Hmac<Sha256>>(b"Jefe", *b"Hi There", &tag).is_err());
+    assert!(verify_mac::<Hmac<Sha256>>(&key, *b"Hi there", &tag).is_err());
+}
+
+#[test]
+fn fingerprint_hmac_long_key() {
+    let key = [0xaau8; 131];
+    let message = *b"Test Using Larger Than Block-Size Key - Hash Key First";
+
+    let tag = fingerprint_mac::<Hmac<Sha256>>(&key, message).unwrap();
+    assert_eq!(
+        hex::encode(tag.into_bytes()),
+        "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+    );
+}
+
+#[test]
+fn fingerprint_keyed_blake2() {
+    let key: Vec<u8> = (0..64).collect();
+
+    let tag = fingerprint_mac::<Blake2bMac512>(&key, ()).unwrap();
+    let tag = tag.into_bytes();
+    assert_eq!(
+        hex::encode(tag),
+        "10ebb67700b1868efb4417987acf4690ae9d972fb7a590c2f02871799aaa4786\
+         b5e996e8f0f4eb981fc214b005f42d2ff4233499391653df7aefcbc13fc51568"
+    );
+
+    assert_eq!(verify_mac::<Blake2bMac512>(&key, (), &tag), Ok(()));
+    assert!(verify_mac::<Blake2bMac512>(&key, 0u8, &tag).is_err());
+}
+
+#[test]
+fn verify_mac_wrong_length() {
+    let key = [0x0bu8; 20];
+    let tag = fingerprint_mac::<Hmac<Sha256>>(&key, *b"Hi There")
+        .unwrap()
+        .into_bytes();
+
+    assert!(verify_mac::<Hmac<Sha256>>(&key, *b"Hi There", &tag[..16]).is_err());
+    assert!(verify_mac::<Hmac<Sha256>>(&key, *b"Hi There", &tag[..31]).is_err());
+    assert!(verify_mac::<Hmac<Sha256>>(&key, *b"Hi There", &[]).is_err());
+
+    let mut long = [0u8; 33];
+    long[..32].copy_from_slice(&tag);
+    assert!(verify_mac::<Hmac<Sha256>>(&key, *b"Hi There", &long).is_err());
+}
+
+#[test]
+fn fingerprint_mac_invalid_key() {
+    let key = [0u8; 65];
+
+    assert!(fingerprint_mac::<Blake2bMac512>(&key, 1u8).is_err());
+    assert!(verify_mac::<Blake2bMac512>(&key, 1u8, &[0; 64]).is_err());
+}
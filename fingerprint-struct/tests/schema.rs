@@ -0,0 +1,236 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+// The derived types are only described, never constructed
+#![allow(dead_code)]
+
+use fingerprint_struct::{Fingerprint, FingerprintSchema, SchemaStack};
+use mock_digest::MockDigest;
+
+fn assert_schema<T: FingerprintSchema + ?Sized, B: Fingerprint>(b: B) {
+    let mut hasher_a = MockDigest::default();
+    SchemaStack::default().fingerprint::<T, _>(&mut hasher_a);
+
+    let mut hasher_b = MockDigest::default();
+    b.fingerprint(&mut hasher_b);
+
+    assert_eq!(hasher_a.as_ref(), hasher_b.as_ref());
+}
+
+fn assert_different_schema<A: FingerprintSchema + ?Sized, B: FingerprintSchema + ?Sized>() {
+    let mut hasher_a = MockDigest::default();
+    SchemaStack::default().fingerprint::<A, _>(&mut hasher_a);
+
+    let mut hasher_b = MockDigest::default();
+    SchemaStack::default().fingerprint::<B, _>(&mut hasher_b);
+
+    assert_ne!(hasher_a.as_ref(), hasher_b.as_ref());
+}
+
+#[test]
+fn schema_primitives() {
+    assert_schema::<u8, _>("u8");
+    assert_schema::<isize, _>("isize");
+    assert_schema::<f64, _>("f64");
+    assert_schema::<str, _>("str");
+    assert_schema::<core::num::NonZeroU32, _>("NonZeroU32");
+}
+
+#[test]
+fn schema_compound_types() {
+    assert_schema::<Option<u8>, _>(("Option", "u8"));
+    assert_schema::<Result<u8, u16>, _>(("Result", "u8", "u16"));
+    assert_schema::<[u8], _>(("slice", "u8"));
+    assert_schema::<[u8; 4], _>(("array", 4usize, "u8"));
+    assert_schema::<(u8, bool), _>(("tuple", 2usize, "u8", "bool"));
+    assert_schema::<(), _>(("tuple", 0usize));
+    assert_schema::<fingerprint_struct::CanonicalFloat<f64, true>, _>((
+        "CanonicalFloat",
+        true,
+        "f64",
+    ));
+}
+
+#[test]
+fn schema_pointers() {
+    assert_schema::<&u8, _>("u8");
+    assert_schema::<&mut str, _>("str");
+    assert_schema::<core::cell::Cell<u8>, _>("u8");
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn schema_collections() {
+    extern crate alloc;
+
+    use alloc::{boxed::Box, collections::BTreeMap, string::String, vec::Vec};
+
+    assert_schema::<Box<u8>, _>("u8");
+    assert_schema::<Vec<String>, _>(("Vec", "String"));
+    assert_schema::<BTreeMap<String, u8>, _>(("BTreeMap", "String", "u8"));
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_schema_struct() {
+    #[derive(FingerprintSchema)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    assert_schema::<Point, _>((
+        ("struct", "Point", None::<&str>, None::<u32>, false),
+        (2usize, ("x", false, 0u8, "i32"), ("y", false, 0u8, "i32")),
+    ));
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_schema_struct_attributes() {
+    fn never<T>(_: &T) -> bool {
+        false
+    }
+
+    #[derive(FingerprintSchema)]
+    #[fingerprint(domain = "example", version = 2, named_fields, sorted_fields)]
+    struct Struct<T> {
+        #[fingerprint(rename = "c")]
+        a: T,
+        b: (),
+        #[fingerprint(skip)]
+        _skipped: u8,
+        #[fingerprint(skip_if = "never")]
+        d: u8,
+        #[fingerprint(as = "u64")]
+        e: u32,
+        #[fingerprint(with = "path::to::function")]
+        f: u8,
+        #[fingerprint(canonical_float)]
+        g: [f32; 2],
+    }
+
+    assert_schema::<Struct<u16>, _>((
+        ("struct", "Struct", Some("example"), Some(2u32), true),
+        (
+            6usize,
+            ("b", false, 0u8, ("tuple", 0usize)),
+            ("c", false, 0u8, "u16"),
+            ("d", true, 0u8, "u8"),
+            ("e", false, 0u8, "u64"),
+            ("f", false, 1u8, "path::to::function"),
+            (
+                "g",
+                false,
+                0u8,
+                ("CanonicalFloat", false, ("array", 2usize, "f32")),
+            ),
+        ),
+    ));
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_schema_enum() {
+    #[derive(FingerprintSchema)]
+    #[repr(u8)]
+    enum Enum {
+        A,
+        #[fingerprint(tag = 5)]
+        B(u16),
+        C {
+            c: bool,
+        },
+    }
+
+    assert_schema::<Enum, _>((
+        ("enum", "Enum", None::<&str>, None::<u32>, false),
+        ("u8", 3usize),
+        ("A", 0u8, 0usize),
+        ("B", 5u8, 1usize, ("0", false, 0u8, "u16")),
+        ("C", 2u8, 1usize, ("c", false, 0u8, "bool")),
+    ));
+
+    #[derive(FingerprintSchema)]
+    #[fingerprint(tag = "name")]
+    enum Named {
+        #[fingerprint(tag = "b")]
+        A,
+    }
+
+    assert_schema::<Named, _>((
+        ("enum", "Named", None::<&str>, None::<u32>, false),
+        ("name", 1usize),
+        ("A", "b", 0usize),
+    ));
+}
+
+#[test]
+#[cfg(all(feature = "derive", feature = "alloc"))]
+fn derive_schema_recursive() {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
+    #[derive(FingerprintSchema)]
+    struct Node<T> {
+        value: T,
+        children: Vec<Node<T>>,
+    }
+
+    assert_schema::<Node<u8>, _>((
+        ("struct", "Node", None::<&str>, None::<u32>, false),
+        (
+            2usize,
+            ("value", false, 0u8, "u8"),
+            ("children", false, 0u8, ("Vec", ("recursive", 1usize))),
+        ),
+    ));
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn derive_schema_changes() {
+    mod before {
+        use fingerprint_struct::FingerprintSchema;
+
+        #[derive(FingerprintSchema)]
+        pub struct Config {
+            pub width: u32,
+            pub height: u32,
+        }
+    }
+
+    mod reordered {
+        use fingerprint_struct::FingerprintSchema;
+
+        #[derive(FingerprintSchema)]
+        pub struct Config {
+            pub height: u32,
+            pub width: u32,
+        }
+    }
+
+    mod retyped {
+        use fingerprint_struct::FingerprintSchema;
+
+        #[derive(FingerprintSchema)]
+        pub struct Config {
+            pub width: u64,
+            pub height: u32,
+        }
+    }
+
+    mod versioned {
+        use fingerprint_struct::FingerprintSchema;
+
+        #[derive(FingerprintSchema)]
+        #[fingerprint(version = 1)]
+        pub struct Config {
+            pub width: u32,
+            pub height: u32,
+        }
+    }
+
+    assert_different_schema::<before::Config, reordered::Config>();
+    assert_different_schema::<before::Config, retyped::Config>();
+    assert_different_schema::<before::Config, versioned::Config>();
+}
@@ -0,0 +1,113 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use blake2::{Blake2b512, Blake2s256};
+use core::cell::Cell;
+
+use fingerprint_struct::{
+    fingerprint, fingerprint_many, fingerprint_many_with, fingerprint_with, Fingerprint, Tee,
+};
+use mock_digest::MockDigest;
+use sha2::{
+    digest::Update,
+    digest::{FixedOutput, Reset},
+    Digest, Sha256, Sha512,
+};
+
+/// Counts how many times it's hashed.
+struct Probe {
+    count: Cell<usize>,
+}
+
+impl Fingerprint for Probe {
+    fn fingerprint<U: Update>(&self, hasher: &mut U) {
+        self.count.set(self.count.get() + 1);
+        "probe".fingerprint(hasher);
+    }
+}
+
+#[test]
+fn tee_updates_both() {
+    let value = (["tee"; 2], 0x0123_4567_89ab_cdefu64);
+
+    let mut hasher = Tee::<MockDigest, Tee<Sha256, Blake2b512>>::default();
+    value.fingerprint(&mut hasher);
+
+    let mut expected = MockDigest::default();
+    value.fingerprint(&mut expected);
+
+    let (mock, rest) = hasher.into_inner();
+    let (sha256, blake2b) = rest.into_inner();
+
+    assert_eq!(mock.as_ref(), expected.as_ref());
+    assert_eq!(sha256.finalize_fixed(), fingerprint::<Sha256>(value));
+    assert_eq!(blake2b.finalize_fixed(), fingerprint::<Blake2b512>(value));
+}
+
+#[test]
+fn tee_reset() {
+    let mut hasher = Tee::new(MockDigest::default(), MockDigest::default());
+    1u8.fingerprint(&mut hasher);
+    hasher.reset();
+    2u8.fingerprint(&mut hasher);
+
+    let (first, second) = hasher.get_ref();
+    assert_eq!(first.as_ref(), [2]);
+    assert_eq!(second.as_ref(), [2]);
+}
+
+#[test]
+fn fingerprint_many_matches_single() {
+    let value = (300usize, -1i32, "many");
+
+    let (sha256,) = fingerprint_many::<(Sha256,)>(value);
+    assert_eq!(sha256, fingerprint::<Sha256>(value));
+
+    let (sha256, blake2b) = fingerprint_many::<(Sha256, Blake2b512)>(value);
+    assert_eq!(sha256, fingerprint::<Sha256>(value));
+    assert_eq!(blake2b, fingerprint::<Blake2b512>(value));
+
+    let (sha256, sha512, blake2b, blake2s) =
+        fingerprint_many::<(Sha256, Sha512, Blake2b512, Blake2s256)>(value);
+    assert_eq!(sha256, fingerprint::<Sha256>(value));
+    assert_eq!(sha512, fingerprint::<Sha512>(value));
+    assert_eq!(blake2b, fingerprint::<Blake2b512>(value));
+    assert_eq!(blake2s, fingerprint::<Blake2s256>(value));
+}
+
+#[test]
+fn fingerprint_many_traverses_once() {
+    let probe = Probe {
+        count: Cell::new(0),
+    };
+
+    let (sha256, sha512, blake2b, blake2s) =
+        fingerprint_many::<(Sha256, Sha512, Blake2b512, Blake2s256)>(&probe);
+    assert_eq!(probe.count.get(), 1);
+
+    assert_eq!(sha256, fingerprint::<Sha256>("probe"));
+    assert_eq!(sha512, fingerprint::<Sha512>("probe"));
+    assert_eq!(blake2b, fingerprint::<Blake2b512>("probe"));
+    assert_eq!(blake2s, fingerprint::<Blake2s256>("probe"));
+}
+
+#[test]
+fn fingerprint_many_with_prefix() {
+    let value = ('ž', 0.5f64);
+
+    let (sha256, blake2b) = fingerprint_many_with(
+        value,
+        (
+            Sha256::new_with_prefix("prefix"),
+            Blake2b512::new_with_prefix("prefix"),
+        ),
+    );
+
+    assert_eq!(
+        sha256,
+        fingerprint_with(value, Sha256::new_with_prefix("prefix"))
+    );
+    assert_eq!(
+        blake2b,
+        fingerprint_with(value, Blake2b512::new_with_prefix("prefix"))
+    );
+}
@@ -0,0 +1,95 @@
+use blake2::Blake2b512;
+use fingerprint_struct::{
+    fingerprint, fingerprint_typed, fingerprint_typed_with, Fingerprint, ParseDigestError,
+    TypedDigest,
+};
+use mock_digest::MockDigest;
+use sha2::{Digest, Sha256};
+
+#[test]
+fn typed_matches_untyped() {
+    let typed: TypedDigest<Blake2b512, str> = fingerprint_typed("Hello world!");
+    let untyped = fingerprint::<Blake2b512>("Hello world!");
+
+    assert_eq!(typed.as_bytes(), untyped.as_slice());
+    assert_eq!(typed.into_output(), untyped);
+
+    let typed: TypedDigest<Sha256, u8> =
+        fingerprint_typed_with(&5u8, Sha256::new_with_prefix("prefix"));
+    let expected = Sha256::new_with_prefix("prefix")
+        .chain_update([5])
+        .finalize();
+
+    assert_eq!(typed.as_bytes(), expected.as_slice());
+}
+
+#[test]
+fn typed_format() {
+    let hash: TypedDigest<Sha256, [u8]> = fingerprint_typed(&[1u8, 2, 3][..]);
+    let expected = hex::encode(hash.as_bytes());
+
+    assert_eq!(hash.to_string(), expected);
+    assert_eq!(format!("{hash:x}"), expected);
+    assert_eq!(format!("{hash:#x}"), format!("0x{expected}"));
+    assert_eq!(format!("{hash:X}"), expected.to_uppercase());
+    assert_eq!(format!("{hash:?}"), format!("TypedDigest({expected})"));
+}
+
+#[test]
+fn typed_parse() {
+    let hash: TypedDigest<Sha256, u32> = fingerprint_typed(&42u32);
+    let text = hash.to_string();
+
+    assert_eq!(text.parse(), Ok(hash));
+    assert_eq!(text.to_uppercase().parse(), Ok(hash));
+
+    assert_eq!(
+        text[2..].parse::<TypedDigest<Sha256, u32>>(),
+        Err(ParseDigestError::InvalidLength)
+    );
+    assert_eq!(
+        format!("{text}00").parse::<TypedDigest<Sha256, u32>>(),
+        Err(ParseDigestError::InvalidLength)
+    );
+    assert_eq!(
+        format!("{hash:#x}").parse::<TypedDigest<Sha256, u32>>(),
+        Err(ParseDigestError::InvalidLength)
+    );
+    assert_eq!(
+        format!("g{}", &text[1..]).parse::<TypedDigest<Sha256, u32>>(),
+        Err(ParseDigestError::InvalidCharacter)
+    );
+    assert_eq!(
+        "".parse::<TypedDigest<Sha256, u32>>(),
+        Err(ParseDigestError::InvalidLength)
+    );
+}
+
+#[test]
+fn typed_compare() {
+    let a: TypedDigest<Sha256, u32> = fingerprint_typed(&1u32);
+    let b: TypedDigest<Sha256, u32> = fingerprint_typed(&2u32);
+
+    assert_eq!(a, a.clone());
+    assert_ne!(a, b);
+    assert_eq!(a.cmp(&b), a.as_bytes().cmp(b.as_bytes()));
+
+    // Every byte is compared
+    let text = a.to_string();
+    let last = if text.ends_with("00") { "01" } else { "00" };
+    let c: TypedDigest<Sha256, u32> = format!("{}{last}", &text[..62]).parse().unwrap();
+    assert_ne!(a, c);
+
+    let set = std::collections::HashSet::from([a, b, a]);
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn typed_fingerprint() {
+    let hash: TypedDigest<Sha256, u32> = fingerprint_typed(&1u32);
+
+    let mut hasher = MockDigest::default();
+    hash.fingerprint(&mut hasher);
+
+    assert_eq!(hasher.as_ref(), hash.as_bytes());
+}
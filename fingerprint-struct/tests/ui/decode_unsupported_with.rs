@@ -0,0 +1,9 @@
+use fingerprint_struct::FingerprintDecode;
+
+#[derive(FingerprintDecode)]
+struct Struct {
+    #[fingerprint(with = "hash_len")]
+    a: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use fingerprint_struct::Fingerprint;
+
+#[derive(Fingerprint)]
+#[fingerprint(version = 1, version = 2)]
+struct Struct {
+    a: u8,
+}
+
+fn main() {}
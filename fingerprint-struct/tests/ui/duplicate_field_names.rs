@@ -0,0 +1,11 @@
+use fingerprint_struct::Fingerprint;
+
+#[derive(Fingerprint)]
+#[fingerprint(named_fields)]
+struct Struct {
+    a: u8,
+    #[fingerprint(rename = "a")]
+    b: u8,
+}
+
+fn main() {}
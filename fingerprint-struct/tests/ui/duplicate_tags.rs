@@ -0,0 +1,10 @@
+use fingerprint_struct::Fingerprint;
+
+#[derive(Fingerprint)]
+enum Enum {
+    A,
+    #[fingerprint(tag = 0)]
+    B,
+}
+
+fn main() {}
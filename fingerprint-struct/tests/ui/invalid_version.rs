@@ -0,0 +1,11 @@
+use fingerprint_struct::Fingerprint;
+
+#[derive(Fingerprint)]
+#[fingerprint(version = "1")]
+struct First;
+
+#[derive(Fingerprint)]
+#[fingerprint(version = -1)]
+struct Second;
+
+fn main() {}
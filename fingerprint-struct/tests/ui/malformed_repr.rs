@@ -0,0 +1,10 @@
+use fingerprint_struct::Fingerprint;
+
+#[derive(Fingerprint)]
+#[repr(u8, "u16")]
+enum Enum {
+    A,
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use fingerprint_struct::Fingerprint;
+
+#[derive(Fingerprint)]
+#[fingerprint(named_fields)]
+struct Struct(u8, u16);
+
+fn main() {}
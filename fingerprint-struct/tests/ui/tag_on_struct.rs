@@ -0,0 +1,9 @@
+use fingerprint_struct::Fingerprint;
+
+#[derive(Fingerprint)]
+#[fingerprint(tag = "name")]
+struct Struct {
+    a: u8,
+}
+
+fn main() {}
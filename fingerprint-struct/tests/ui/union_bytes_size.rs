@@ -0,0 +1,10 @@
+use fingerprint_struct::Fingerprint;
+
+#[derive(Fingerprint)]
+#[fingerprint(bytes)]
+union Union {
+    a: [u8; 3],
+    b: u16,
+}
+
+fn main() {}
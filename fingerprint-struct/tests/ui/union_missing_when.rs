@@ -0,0 +1,11 @@
+use fingerprint_struct::Fingerprint;
+
+#[derive(Fingerprint)]
+#[fingerprint(unsafe_discriminant = "0u8")]
+union Union {
+    #[fingerprint(when = "0")]
+    a: u32,
+    b: f32,
+}
+
+fn main() {}
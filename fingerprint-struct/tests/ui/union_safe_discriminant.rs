@@ -0,0 +1,10 @@
+use fingerprint_struct::Fingerprint;
+
+#[derive(Fingerprint)]
+#[fingerprint(discriminant = "0u8")]
+union Union {
+    #[fingerprint(when = "0")]
+    a: u32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use fingerprint_struct::Fingerprint;
+
+#[derive(Fingerprint)]
+union Union {
+    a: u32,
+    b: f32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use fingerprint_struct::Fingerprint;
+
+#[derive(Fingerprint)]
+struct Struct {
+    #[fingerprint(version = 1)]
+    a: u8,
+}
+
+fn main() {}
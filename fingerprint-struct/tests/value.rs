@@ -0,0 +1,290 @@
+#![cfg(feature = "alloc")]
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec,
+    vec::Vec,
+};
+
+use fingerprint_struct::{value::Value, Fingerprint};
+use mock_digest::MockDigest;
+
+fn assert_same_fingerprint<T1: Fingerprint + ?Sized, T2: Fingerprint + ?Sized>(a: &T1, b: &T2) {
+    let mut hasher_a = MockDigest::default();
+    let mut hasher_b = MockDigest::default();
+
+    a.fingerprint(&mut hasher_a);
+    b.fingerprint(&mut hasher_b);
+
+    assert_eq!(hasher_a.as_ref(), hasher_b.as_ref());
+}
+
+#[test]
+fn value_primitives() {
+    assert_same_fingerprint(&Value::Unit, &());
+    assert_same_fingerprint(&Value::Bool(true), &true);
+    assert_same_fingerprint(&Value::U8(1), &1u8);
+    assert_same_fingerprint(&Value::U16(0x1234), &0x1234u16);
+    assert_same_fingerprint(&Value::U32(0x1234_5678), &0x1234_5678u32);
+    assert_same_fingerprint(&Value::U64(u64::MAX), &u64::MAX);
+    assert_same_fingerprint(&Value::U128(u128::MAX), &u128::MAX);
+    assert_same_fingerprint(&Value::Usize(300), &300usize);
+    assert_same_fingerprint(&Value::I8(-1), &-1i8);
+    assert_same_fingerprint(&Value::I16(-300), &-300i16);
+    assert_same_fingerprint(&Value::I32(i32::MIN), &i32::MIN);
+    assert_same_fingerprint(&Value::I64(-5), &-5i64);
+    assert_same_fingerprint(&Value::I128(i128::MIN), &i128::MIN);
+    assert_same_fingerprint(&Value::Isize(-300), &-300isize);
+    assert_same_fingerprint(&Value::F32(1.5), &1.5f32);
+    assert_same_fingerprint(&Value::F64(f64::NAN), &f64::NAN);
+    assert_same_fingerprint(&Value::Char('ř'), &'ř');
+}
+
+#[test]
+fn value_strings_and_bytes() {
+    assert_same_fingerprint(&Value::String("příklad".into()), "příklad");
+    assert_same_fingerprint(&Value::String("příklad".into()), &String::from("příklad"));
+    assert_same_fingerprint(&Value::Bytes(vec![1, 2, 3]), &[1u8, 2, 3][..]);
+    assert_same_fingerprint(&Value::Bytes(vec![1, 2, 3]), &vec![1u8, 2, 3]);
+    assert_same_fingerprint(
+        &Value::Bytes(vec![1, 2, 3]),
+        &Value::Seq(vec![Value::U8(1), Value::U8(2), Value::U8(3)]),
+    );
+}
+
+#[test]
+fn value_sequences() {
+    assert_same_fingerprint(
+        &Value::Seq(vec![Value::U32(1), Value::U32(2)]),
+        &vec![1u32, 2],
+    );
+    assert_same_fingerprint(&Value::Seq(vec![]), &Vec::<u32>::new());
+    assert_same_fingerprint(
+        &Value::Tuple(vec![Value::U32(1), Value::U32(2)]),
+        &[1u32, 2],
+    );
+    assert_same_fingerprint(
+        &Value::Tuple(vec![Value::U8(1), "a".into(), Value::Char('b')]),
+        &(1u8, "a", 'b'),
+    );
+    assert_same_fingerprint(
+        &Value::Seq(vec![
+            Value::Tuple(vec![Value::U8(1), "a".into()]),
+            Value::Tuple(vec![Value::U8(2), "b".into()]),
+        ]),
+        &vec![(1u8, "a"), (2u8, "b")],
+    );
+}
+
+#[test]
+fn value_options() {
+    assert_same_fingerprint(&Value::from(Some(5u32)), &Some(5u32));
+    assert_same_fingerprint(&Value::from(None::<u32>), &None::<u32>);
+    assert_same_fingerprint(
+        &Value::Option(Some(Box::new(Value::from(None::<u8>)))),
+        &Some(None::<u8>),
+    );
+}
+
+#[test]
+fn value_sets_and_maps() {
+    assert_same_fingerprint(
+        &Value::Set(BTreeSet::from([
+            Value::I32(3),
+            Value::I32(-1),
+            Value::I32(2),
+        ])),
+        &BTreeSet::from([3i32, -1, 2]),
+    );
+    assert_same_fingerprint(
+        &Value::Set(BTreeSet::from(["b".into(), "ab".into(), "a".into()])),
+        &BTreeSet::from(["b", "ab", "a"]),
+    );
+    assert_same_fingerprint(
+        &Value::Map(BTreeMap::from([
+            (Value::U16(300), "c".into()),
+            (Value::U16(1), "a".into()),
+            (Value::U16(2), "b".into()),
+        ])),
+        &BTreeMap::from([(300u16, "c"), (1u16, "a"), (2u16, "b")]),
+    );
+    assert_same_fingerprint(
+        &Value::Map(BTreeMap::from([(
+            Value::Tuple(vec![Value::U8(1), Value::Bool(false)]),
+            Value::Seq(vec![]),
+        )])),
+        &BTreeMap::from([((1u8, false), Vec::<u8>::new())]),
+    );
+}
+
+#[test]
+fn value_sets_and_maps_duplicates() {
+    assert_same_fingerprint(
+        &Value::Set(BTreeSet::from([Value::U8(1), Value::U8(2), Value::U8(1)])),
+        &BTreeSet::from([1u8, 2, 1]),
+    );
+    assert_same_fingerprint(
+        &Value::Set(BTreeSet::from([Value::F64(f64::NAN), Value::F64(f64::NAN)])),
+        &(1usize, f64::NAN),
+    );
+    assert_same_fingerprint(
+        &Value::Map(BTreeMap::from([
+            (Value::U16(1), "a".into()),
+            (Value::U16(2), "b".into()),
+            (Value::U16(1), "c".into()),
+        ])),
+        &BTreeMap::from([(1u16, "a"), (2u16, "b"), (1u16, "c")]),
+    );
+    assert_same_fingerprint(
+        &Value::Map(
+            [(Value::U16(1), "a".into()), (Value::U16(1), "c".into())]
+                .into_iter()
+                .collect(),
+        ),
+        &(1usize, 1u16, "c"),
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn value_hash_collections() {
+    use std::collections::{HashMap, HashSet};
+
+    assert_same_fingerprint(
+        &Value::Set(BTreeSet::from([
+            Value::I64(-5),
+            Value::I64(7),
+            Value::I64(0),
+        ])),
+        &HashSet::from([-5i64, 7, 0]),
+    );
+    assert_same_fingerprint(
+        &Value::Map(BTreeMap::from([
+            (Value::Char('z'), Value::U8(1)),
+            (Value::Char('a'), Value::U8(2)),
+        ])),
+        &HashMap::from([('z', 1u8), ('a', 2u8)]),
+    );
+}
+
+#[test]
+fn value_std_enums() {
+    assert_same_fingerprint(
+        &Value::Variant {
+            tag: Box::new(Value::U8(0)),
+            fields: vec![Value::U32(5)],
+        },
+        &Ok::<u32, String>(5),
+    );
+    assert_same_fingerprint(
+        &Value::Variant {
+            tag: Box::new(Value::U8(1)),
+            fields: vec!["error".into()],
+        },
+        &Err::<u32, &str>("error"),
+    );
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn value_derived_types() {
+    #[derive(Fingerprint)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Fingerprint)]
+    #[fingerprint(domain, version = 2, named_fields)]
+    struct Config {
+        name: &'static str,
+        points: Vec<Point>,
+    }
+
+    #[derive(Fingerprint)]
+    enum Shape {
+        Empty,
+        Circle { radius: u32 },
+    }
+
+    #[derive(Fingerprint)]
+    #[repr(u8)]
+    enum Small {
+        A = 3,
+    }
+
+    #[derive(Fingerprint)]
+    #[fingerprint(tag = "name")]
+    enum Named {
+        First(bool),
+    }
+
+    assert_same_fingerprint(
+        &Value::Tuple(vec![Value::I32(1), Value::I32(-2)]),
+        &Point { x: 1, y: -2 },
+    );
+    assert_same_fingerprint(
+        &Value::Tuple(vec![
+            "Config".into(),
+            Value::U32(2),
+            "name".into(),
+            "test".into(),
+            "points".into(),
+            Value::Seq(vec![Value::Tuple(vec![Value::I32(1), Value::I32(2)])]),
+        ]),
+        &Config {
+            name: "test",
+            points: vec![Point { x: 1, y: 2 }],
+        },
+    );
+    assert_same_fingerprint(
+        &Value::Variant {
+            tag: Box::new(Value::Isize(0)),
+            fields: vec![],
+        },
+        &Shape::Empty,
+    );
+    assert_same_fingerprint(
+        &Value::Variant {
+            tag: Box::new(Value::Isize(1)),
+            fields: vec![Value::U32(5)],
+        },
+        &Shape::Circle { radius: 5 },
+    );
+    assert_same_fingerprint(
+        &Value::Variant {
+            tag: Box::new(Value::U8(3)),
+            fields: vec![],
+        },
+        &Small::A,
+    );
+    assert_same_fingerprint(
+        &Value::Variant {
+            tag: Box::new("First".into()),
+            fields: vec![Value::Bool(true)],
+        },
+        &Named::First(true),
+    );
+}
+
+#[test]
+fn value_ordering() {
+    assert!(Value::I32(-1) < Value::I32(1));
+    assert!(Value::from("a") < Value::from("ab"));
+    assert!(Value::from(None::<u8>) < Value::from(Some(0u8)));
+    assert!(Value::F64(-0.0) < Value::F64(0.0));
+    assert_eq!(Value::F64(f64::NAN), Value::F64(f64::NAN));
+    assert_eq!(
+        Value::Set(BTreeSet::from([Value::U8(1), Value::U8(2)])),
+        Value::Set(BTreeSet::from([Value::U8(2), Value::U8(1)]))
+    );
+    assert!(
+        Value::Set(BTreeSet::from([Value::U8(1), Value::U8(3)]))
+            < Value::Set(BTreeSet::from([Value::U8(2)]))
+    );
+    assert_ne!(Value::U8(1), Value::U16(1));
+}
@@ -0,0 +1,82 @@
+use blake2::{Blake2bVar, Blake2sVar};
+use fingerprint_struct::{fingerprint_var_into, fingerprint_xof, fingerprint_xof_into};
+use sha3::{digest::XofReader, Shake128, Shake256};
+
+#[test]
+fn fingerprint_shake() {
+    let mut hash = [0u8; 32];
+    fingerprint_xof_into::<Shake128>(*b"abc", &mut hash);
+    assert_eq!(
+        hex::encode(hash),
+        "5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc8"
+    );
+
+    let mut reader = fingerprint_xof::<Shake128>(*b"abc");
+    let mut hash = [0u8; 32];
+    reader.read(&mut hash[..5]);
+    reader.read(&mut hash[5..]);
+    assert_eq!(
+        hex::encode(hash),
+        "5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc8"
+    );
+
+    let mut hash = [0u8; 64];
+    fingerprint_xof_into::<Shake256>(*b"abc", &mut hash);
+    assert_eq!(
+        hex::encode(hash),
+        "483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739\
+         d5a15bef186a5386c75744c0527e1faa9f8726e462a12a4feb06bd8801e751e4"
+    );
+}
+
+#[test]
+fn fingerprint_shake_empty() {
+    let mut hash = [0u8; 32];
+    fingerprint_xof_into::<Shake128>((), &mut hash);
+    assert_eq!(
+        hex::encode(hash),
+        "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26"
+    );
+
+    fingerprint_xof_into::<Shake128>(1u8, &mut []);
+}
+
+#[test]
+fn fingerprint_blake2_var() {
+    let mut hash = [0u8; 64];
+    fingerprint_var_into::<Blake2bVar>(*b"abc", &mut hash).unwrap();
+    assert_eq!(
+        hex::encode(hash),
+        "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1\
+         7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+    );
+
+    #[cfg(feature = "alloc")]
+    assert_eq!(
+        fingerprint_struct::fingerprint_var::<Blake2bVar>(*b"abc", 64).unwrap(),
+        hash
+    );
+
+    let mut hash = [0u8; 32];
+    fingerprint_var_into::<Blake2sVar>(*b"abc", &mut hash).unwrap();
+    assert_eq!(
+        hex::encode(hash),
+        "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982"
+    );
+
+    // The output size is part of the parameters, so shorter outputs aren't prefixes of longer ones
+    let mut short = [0u8; 20];
+    fingerprint_var_into::<Blake2bVar>(*b"abc", &mut short).unwrap();
+    assert_eq!(
+        hex::encode(short),
+        "384264f676f39536840523f284921cdc68b6846b"
+    );
+}
+
+#[test]
+fn fingerprint_var_invalid_size() {
+    assert!(fingerprint_var_into::<Blake2bVar>(1u8, &mut [0u8; 65]).is_err());
+    #[cfg(feature = "alloc")]
+    assert!(fingerprint_struct::fingerprint_var::<Blake2bVar>(1u8, 65).is_err());
+    assert!(fingerprint_var_into::<Blake2sVar>(1u8, &mut [0u8; 33]).is_err());
+}